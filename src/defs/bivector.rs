@@ -1,4 +1,4 @@
-use std::{fmt::Display, ops::Mul};
+use core::{fmt::Display, ops::Mul};
 
 use num_traits::Float;
 
@@ -27,11 +27,30 @@ impl<N: Float> Bivector<N> {
         }
     }
 
-    pub fn new(e01: N, e20: N, e12: N) -> Self {
+    /// Create a new bivector.  This is a `const fn`, so it can be used to build `const` and `static` points.
+    pub const fn new(e01: N, e20: N, e12: N) -> Self {
         Bivector { e01, e20, e12 }
     }
 }
 
+/// Basis constants.  `N::zero()` can't be called in a const context, so these are defined for f32 and f64 specifically.
+macro_rules! bivector_constants {
+    ($t:ty) => {
+        impl Bivector<$t> {
+            /// A bivector with all coefficients set to zero.
+            pub const ZERO: Self = Bivector::new(0.0, 0.0, 0.0);
+            /// The basis bivector e₀₁.  The direction of the y axis, as a point at infinity.
+            pub const E01: Self = Bivector::new(1.0, 0.0, 0.0);
+            /// The basis bivector e₂₀.  The direction of the x axis, as a point at infinity.
+            pub const E20: Self = Bivector::new(0.0, 1.0, 0.0);
+            /// The basis bivector e₁₂.  The origin.
+            pub const E12: Self = Bivector::new(0.0, 0.0, 1.0);
+        }
+    };
+}
+bivector_constants!(f32);
+bivector_constants!(f64);
+
 // Conversions
 impl<N: Float> Bivector<N> {
     /// Get this bivector as a multivector struct.
@@ -51,7 +70,7 @@ impl<N: Float> Bivector<N> {
 }
 
 impl<N: Float+Display> Display for Bivector<N> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{{ {}e₀₁, {}e₂₀, {}e₁₂ }}", self.e01, self.e20, self.e12)
     }
 }
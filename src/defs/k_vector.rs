@@ -1,4 +1,4 @@
-use std::fmt::Display;
+use core::fmt::Display;
 
 use num_traits::Float;
 use crate::{defs::vector::Vector, traits::OuterProduct};
@@ -19,7 +19,7 @@ pub enum KVector<N: Float> {
 
 /// Display trait for k-vector.  Simply displays whatever it's wrapped.
 impl<N: Float+Display> Display for KVector<N> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             KVector::Scalar(s) => write!(f, "{}", *s),
             KVector::Vector(v) => write!(f, "{}", v),
@@ -36,28 +36,28 @@ impl<N: Float> KVector<N> {
         if let KVector::Scalar(s) = *self {
             return Result::Ok(s);
         }
-        return Err("Illegal scalar cast!");
+        return Err("Illegal scalar cast!")
     }
     /// Attempt to cast this K-Vector to a 1-vector.
     pub fn to_vector(&self) -> Result<Vector<N>, &'static str> {
         if let KVector::Vector(v) = self {
             return Result::Ok(v.clone());
         }
-        return Err("Illegal vector cast!");
+        return Err("Illegal vector cast!")
     }
     /// Attempt to cast this K-Vector to a bivector.
     pub fn to_bivector(&self) -> Result<Bivector<N>, &'static str> {
         if let KVector::Bivector(b) = self {
             return Result::Ok(b.clone());
         }
-        return Err("Illegal bivector cast!");
+        return Err("Illegal bivector cast!")
     }
     /// Attempt to cast this K-Vector to a trivector.
     pub fn to_trivector(&self) -> Result<Trivector<N>, &'static str> {
         if let KVector::Trivector(t) = self {
             return Result::Ok(t.clone());
         }
-        return Err("Illegal trivector cast!");
+        return Err("Illegal trivector cast!")
     }
 }
 
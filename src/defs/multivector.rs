@@ -1,4 +1,4 @@
-use std::fmt::Display;
+use core::fmt::Display;
 
 use num_traits::Float;
 use crate::{traits::{GeometricProduct, GradeProjection, Dagger, GradeInvolution, MagnitudeSqr}};
@@ -30,6 +30,12 @@ impl<N: Float> Multivector<N> {
         }
     }
 
+    /// Build a multivector out of all four of its parts.
+    /// This is a `const fn`, so it can be used to build `const` and `static` multivectors.
+    pub const fn from_parts(scalar: N, vector: Vector<N>, bivector: Bivector<N>, trivector: Trivector<N>) -> Multivector<N> {
+        Multivector { scalar, vector, bivector, trivector }
+    }
+
     /// Create a multivector with only a scalar component.
     pub fn from_scalar(scalar: N)  -> Multivector<N> {
         Multivector { 
@@ -71,9 +77,38 @@ impl<N: Float> Multivector<N> {
     }
 }
 
+/// Basis constants, as multivectors.  `N::zero()` can't be called in a const context, so these are defined 
+/// for f32 and f64 specifically.
+macro_rules! multivector_constants {
+    ($t:ty) => {
+        impl Multivector<$t> {
+            /// A multivector with all components zero'd out.
+            pub const ZERO: Self = Multivector::from_parts(0.0, Vector::<$t>::ZERO, Bivector::<$t>::ZERO, Trivector::<$t>::ZERO);
+            /// The scalar one.
+            pub const ONE: Self = Multivector::from_parts(1.0, Vector::<$t>::ZERO, Bivector::<$t>::ZERO, Trivector::<$t>::ZERO);
+            /// The basis vector e₀.
+            pub const E0: Self = Multivector::from_parts(0.0, Vector::<$t>::E0, Bivector::<$t>::ZERO, Trivector::<$t>::ZERO);
+            /// The basis vector e₁.
+            pub const E1: Self = Multivector::from_parts(0.0, Vector::<$t>::E1, Bivector::<$t>::ZERO, Trivector::<$t>::ZERO);
+            /// The basis vector e₂.
+            pub const E2: Self = Multivector::from_parts(0.0, Vector::<$t>::E2, Bivector::<$t>::ZERO, Trivector::<$t>::ZERO);
+            /// The basis bivector e₀₁.
+            pub const E01: Self = Multivector::from_parts(0.0, Vector::<$t>::ZERO, Bivector::<$t>::E01, Trivector::<$t>::ZERO);
+            /// The basis bivector e₂₀.
+            pub const E20: Self = Multivector::from_parts(0.0, Vector::<$t>::ZERO, Bivector::<$t>::E20, Trivector::<$t>::ZERO);
+            /// The basis bivector e₁₂.
+            pub const E12: Self = Multivector::from_parts(0.0, Vector::<$t>::ZERO, Bivector::<$t>::E12, Trivector::<$t>::ZERO);
+            /// The unit pseudoscalar e₀₁₂.
+            pub const E012: Self = Multivector::from_parts(0.0, Vector::<$t>::ZERO, Bivector::<$t>::ZERO, Trivector::<$t>::E012);
+        }
+    };
+}
+multivector_constants!(f32);
+multivector_constants!(f64);
+
 /// Multivectors implement the display attribute, so long as the float they're defined with also implement Display.
 impl<N: Float+Display> Display for Multivector<N> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let scalar = self.scalar;
         let vector = &self.vector;
         let bivector = &self.bivector;
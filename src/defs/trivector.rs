@@ -1,4 +1,4 @@
-use std::{fmt::Display, ops::Mul};
+use core::{fmt::Display, ops::Mul};
 
 use num_traits::Float;
 
@@ -21,7 +21,8 @@ impl<N: Float> Trivector<N> {
     }
 
     /// Build a trivector with an arbitrary e012 coefficient.
-    pub fn new(e012: N) -> Self {
+    /// This is a `const fn`, so it can be used in `const` and `static` items.
+    pub const fn new(e012: N) -> Self {
         Trivector { e012 }
     }
 
@@ -31,6 +32,20 @@ impl<N: Float> Trivector<N> {
     }
 }
 
+/// Basis constants.  `N::zero()` can't be called in a const context, so these are defined for f32 and f64 specifically.
+macro_rules! trivector_constants {
+    ($t:ty) => {
+        impl Trivector<$t> {
+            /// A trivector with e012 set to zero.
+            pub const ZERO: Self = Trivector::new(0.0);
+            /// The unit pseudoscalar e₀₁₂.
+            pub const E012: Self = Trivector::new(1.0);
+        }
+    };
+}
+trivector_constants!(f32);
+trivector_constants!(f64);
+
 // Conversions
 
 impl<N: Float> Trivector<N> {
@@ -51,7 +66,7 @@ impl<N: Float> Trivector<N> {
 }
 
 impl<N: Float+Display> Display for Trivector<N> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{{ {}e₀₁₂ }}", self.e012 )
     }
 }
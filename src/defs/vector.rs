@@ -1,4 +1,4 @@
-use std::{fmt::Display, ops::Mul};
+use core::{fmt::Display, ops::Mul};
 
 use num_traits::Float;
 
@@ -27,13 +27,32 @@ impl<N: Float> Vector<N> {
     }
 
     /// Create a new vector.  Note: In 2D PGA, a 'vector' represents a line with direction and magnitude.  Not an arrow.
-    pub fn new(e0: N, e1: N, e2: N) -> Self {
+    /// This is a `const fn`, so it can be used to build `const` and `static` lines.
+    pub const fn new(e0: N, e1: N, e2: N) -> Self {
         Vector {
             e0, e1, e2
         }
     }
 }
 
+/// Basis constants.  `N::zero()` can't be called in a const context, so these are defined for f32 and f64 specifically.
+macro_rules! vector_constants {
+    ($t:ty) => {
+        impl Vector<$t> {
+            /// A vector with all coefficients set to zero.
+            pub const ZERO: Self = Vector::new(0.0, 0.0, 0.0);
+            /// The basis vector e₀.  The line at infinity.
+            pub const E0: Self = Vector::new(1.0, 0.0, 0.0);
+            /// The basis vector e₁.  The line x=0.
+            pub const E1: Self = Vector::new(0.0, 1.0, 0.0);
+            /// The basis vector e₂.  The line y=0.
+            pub const E2: Self = Vector::new(0.0, 0.0, 1.0);
+        }
+    };
+}
+vector_constants!(f32);
+vector_constants!(f64);
+
 // Conversions
 
 impl<N: Float> Vector<N> {
@@ -54,7 +73,7 @@ impl<N: Float> Vector<N> {
 }
 
 impl<N: Float+Display> Display for Vector<N> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{{ {}e₀, {}e₁, {}e₂ }}", self.e0, self.e1, self.e2)
     }
 }
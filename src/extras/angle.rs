@@ -1,4 +1,4 @@
-use std::{ops::Add, f32::consts::PI};
+use core::{ops::Add, f32::consts::PI};
 
 use num_traits::Float;
 
@@ -10,7 +10,8 @@ pub struct Angle<N: Float> {
 }
 
 impl<N: Float> Angle<N> {
-    pub fn from_radians(radians: N) -> Angle<N> {
+    /// This is a `const fn`, so it can be used to build `const` and `static` angles.
+    pub const fn from_radians(radians: N) -> Angle<N> {
         Angle { radians }
     }
 
@@ -22,7 +23,7 @@ impl<N: Float> Angle<N> {
 /// Constructors and getters.
 impl Angle<f32> {
 
-    pub fn from_degrees(degrees: f32) -> Angle<f32> {
+    pub const fn from_degrees(degrees: f32) -> Angle<f32> {
         Angle {
             radians: degrees * (PI/180.0f32)
         }        
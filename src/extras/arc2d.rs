@@ -0,0 +1,163 @@
+use num_traits::{Float, FloatConst};
+
+use crate::defs::bivector::Bivector;
+
+use super::{
+    angle::Angle,
+    circle2d::{Circle2d, CircleIntersection},
+    line2d::{Line2d, default_tolerance},
+    point2d::Point2d,
+    segment2d::Segment2d,
+    transformations::RigidTransformation,
+};
+
+/// An arc of a circle.  It starts at `start` (measured counterclockwise from the x axis) and sweeps through `sweep`,
+/// counterclockwise if that's positive and clockwise if it's negative.  A sweep of a full turn or more is the whole
+/// circle.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Arc2d<N: Float> {
+    pub center: Point2d<N>,
+    pub radius: N,
+    pub start: Angle<N>,
+    pub sweep: Angle<N>,
+}
+
+// Constructors and conversions for arc 2d //
+
+impl<N: Float> Arc2d<N> {
+    /// Create a new arc.
+    /// This is a `const fn`, so it can be used to build `const` and `static` arcs.
+    pub const fn new(center: Point2d<N>, radius: N, start: Angle<N>, sweep: Angle<N>) -> Arc2d<N> {
+        Arc2d { center, radius, start, sweep }
+    }
+
+    /// The circle this arc is part of.
+    pub fn circle(&self) -> Circle2d<N> {
+        Circle2d::new(self.center, self.radius)
+    }
+
+    /// The point on this arc's circle at `angle`.
+    pub fn point_at(&self, angle: Angle<N>) -> Point2d<N> {
+        let radians = angle.get_radians();
+        Point2d::new(
+            self.center.x + self.radius * radians.cos(),
+            self.center.y + self.radius * radians.sin(),
+        )
+    }
+
+    /// The first end of this arc.
+    pub fn start_point(&self) -> Point2d<N> {
+        self.point_at(self.start)
+    }
+
+    /// The last end of this arc.
+    pub fn end_point(&self) -> Point2d<N> {
+        self.point_at(self.start + self.sweep)
+    }
+
+    /// The length along this arc.
+    pub fn length(&self) -> N {
+        self.radius * self.sweep.get_radians().abs()
+    }
+}
+
+// Queries //
+
+impl<N: Float + FloatConst> Arc2d<N> {
+    /// Whether the direction at `angle` from the center is within this arc's sweep, with the default tolerance.
+    pub fn contains_angle(&self, angle: Angle<N>) -> bool {
+        self.contains_angle_with_tolerance(angle, default_tolerance())
+    }
+
+    /// Whether the direction at `angle` from the center is within this arc's sweep, allowing `tolerance` radians
+    /// past either end.
+    pub fn contains_angle_with_tolerance(&self, angle: Angle<N>, tolerance: N) -> bool {
+        let turn = N::TAU();
+        let sweep = self.sweep.get_radians();
+        if sweep.abs() + tolerance >= turn {
+            return true;
+        }
+        // How far past the start the angle is, going the same way as the arc, in [0, 2π).
+        let mut offset = (angle.get_radians() - self.start.get_radians()) * sweep.signum();
+        offset = offset - (offset / turn).floor() * turn;
+        offset <= sweep.abs() + tolerance || offset >= turn - tolerance
+    }
+
+    /// Whether a point on (or near) this arc's circle is within its sweep.  The angle of the point is taken about
+    /// the center, so its distance from the circle is ignored.
+    pub fn covers(&self, point: &Point2d<N>) -> bool {
+        let angle = (point.y - self.center.y).atan2(point.x - self.center.x);
+        self.contains_angle(Angle::from_radians(angle))
+    }
+
+    /// Intersect this arc with a line.  See `Circle2d::intersect_line`.
+    pub fn intersect_line(&self, line: &Line2d<N>) -> CircleIntersection<N> {
+        self.circle().intersect_line(line).filter(|p| self.covers(p))
+    }
+
+    /// Intersect this arc with a segment.  See `Circle2d::intersect_segment`.
+    pub fn intersect_segment(&self, segment: &Segment2d<N>) -> CircleIntersection<N> {
+        self.circle().intersect_segment(segment).filter(|p| self.covers(p))
+    }
+
+    /// Intersect this arc with a circle.  See `Circle2d::intersect_circle`.
+    /// If the arc lies on the circle, they're coincident, unless the arc has no sweep, when it's just its one point.
+    pub fn intersect_circle(&self, circle: &Circle2d<N>) -> CircleIntersection<N> {
+        match self.circle().intersect_circle(circle) {
+            CircleIntersection::Coincident if self.sweep.get_radians() == N::zero() => CircleIntersection::One(self.start_point()),
+            crossings => crossings.filter(|p| self.covers(p)),
+        }
+    }
+
+    /// Intersect two arcs.  See `Circle2d::intersect_circle`.
+    /// Arcs on the same circle are coincident if their sweeps overlap.  If they only meet at their ends, those are
+    /// the points, and if they don't meet at all, there are none.
+    pub fn intersect_arc(&self, other: &Arc2d<N>) -> CircleIntersection<N> {
+        match self.circle().intersect_circle(&other.circle()) {
+            CircleIntersection::Coincident => self.intersect_on_circle(other),
+            crossings => crossings.filter(|p| self.covers(p) && other.covers(p)),
+        }
+    }
+
+    /// Where two arcs of the same circle meet.  Their sweeps overlap if an end of either is inside the other, rather
+    /// than at one of its ends.  If not, they can still be the same arc (either way around), which is when the
+    /// middle of one is on the other.  Otherwise, they only meet at the ends they share.
+    fn intersect_on_circle(&self, other: &Arc2d<N>) -> CircleIntersection<N> {
+        for (single, arc) in [(self, other), (other, self)] {
+            if single.sweep.get_radians() == N::zero() {
+                return CircleIntersection::One(single.start_point()).filter(|p| arc.covers(p));
+            }
+        }
+
+        let tolerance = default_tolerance::<N>() * self.radius;
+        let ends = |arc: &Arc2d<N>| [arc.start_point(), arc.end_point()];
+        let inside = |arc: &Arc2d<N>, p: &Point2d<N>| arc.covers(p) && ends(arc).iter().all(|end| end.distance_to(p) > tolerance);
+        let two = N::one() + N::one();
+        let middle = self.point_at(Angle::from_radians(self.start.get_radians() + self.sweep.get_radians() / two));
+        if ends(other).iter().any(|p| inside(self, p)) || ends(self).iter().any(|p| inside(other, p)) || other.covers(&middle) {
+            return CircleIntersection::Coincident;
+        }
+
+        match ends(self) {
+            [start, end] if other.covers(&start) && other.covers(&end) => CircleIntersection::Two(start, end),
+            [start, _] if other.covers(&start) => CircleIntersection::One(start),
+            [_, end] if other.covers(&end) => CircleIntersection::One(end),
+            _ => CircleIntersection::None,
+        }
+    }
+}
+
+/// Anything which can transform a bivector can transform an arc.  The start angle follows the transformed start
+/// point, and the sweep is unchanged.
+impl<N: Float, T: RigidTransformation<Bivector<N>>> RigidTransformation<Arc2d<N>> for T {
+    fn apply(&self, target: &Arc2d<N>) -> Arc2d<N> {
+        let center = Point2d::from_bivector(&self.apply(&target.center.to_bivector()));
+        let start = Point2d::from_bivector(&self.apply(&target.start_point().to_bivector()));
+        Arc2d {
+            center,
+            radius: target.radius,
+            start: Angle::from_radians((start.y - center.y).atan2(start.x - center.x)),
+            sweep: target.sweep,
+        }
+    }
+}
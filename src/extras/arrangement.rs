@@ -0,0 +1,437 @@
+use alloc::{collections::BTreeMap, vec, vec::Vec};
+
+use core::cmp::Ordering;
+
+use num_traits::Float;
+
+use crate::{defs::{vector::Vector, bivector::Bivector}, traits::OuterProduct};
+
+use super::{line2d::Line2d, point2d::Point2d, predicates::{orient_lines, side_of_line, turn_between, Orientation}};
+
+// LINE ARRANGEMENTS //
+// The plane cut up by a set of lines, as a doubly connected edge list (DCEL).  Every pair of lines meets, at a
+// point at infinity if they're parallel, so every line runs from one point at infinity to the opposite one, through
+// its meets with the others.  The points at infinity are joined up around the line at infinity as well, which
+// closes off the unbounded faces, so every face is a cycle of half-edges, bounded or not.
+// Nothing is decided with rounded coordinates: where meets are along a line, and which meets are the same point,
+// come from the exact `predicates::orient_lines` and `predicates::turn_between`.  So three lines through one point
+// make one vertex, and parallel lines share their points at infinity.
+// Requires the `alloc` feature.
+
+/// One side of an edge.  Its face is on its left.  Requires the `alloc` feature.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HalfEdge {
+    /// The vertex it starts from.
+    pub origin: usize,
+    /// The other side of the same edge, going the other way.
+    pub twin: usize,
+    /// The next half-edge around its face, counterclockwise.
+    pub next: usize,
+    /// The previous half-edge around its face.
+    pub previous: usize,
+    /// The face on its left.  Half-edges along the line at infinity, facing out of the plane, have none.
+    pub face: Option<usize>,
+    /// The index of the line it's part of, or `None` for the line at infinity.
+    pub line: Option<usize>,
+}
+
+/// A region of the plane with no lines through it.  Requires the `alloc` feature.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Face {
+    /// A half-edge on its boundary.  Only the whole plane, with no lines at all, has none.
+    pub edge: Option<usize>,
+    /// Whether the face is a polygon, rather than reaching the line at infinity.
+    pub bounded: bool,
+}
+
+/// Where a point is in an arrangement.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Location {
+    /// Inside a face.
+    Face(usize),
+    /// On an edge, between its vertices.  This is the half-edge which points the same way as its line.
+    Edge(usize),
+    /// On a vertex.
+    Vertex(usize),
+}
+
+/// A set of lines and the faces, edges and vertices they cut the plane into.  Requires the `alloc` feature.
+///
+/// Vertices are normalized bivectors for meets of lines, and unit points at infinity (e12 = 0) for the ends of the
+/// lines.  The two half-edges of each edge are next to each other: the twin of half-edge `i` is `i ^ 1`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Arrangement<N: Float> {
+    lines: Vec<Vector<N>>,
+    vertices: Vec<Bivector<N>>,
+    half_edges: Vec<HalfEdge>,
+    faces: Vec<Face>,
+    /// Each vertex where lines meet, by the two lowest indices of the lines through it.
+    meets: BTreeMap<(usize, usize), usize>,
+    /// The lines used, which leaves out lines with no direction, and any line which is the same as an earlier one.
+    used: Vec<usize>,
+    /// For each line, the vertices where it meets others in order along it, each with another line through them.
+    along: Vec<Vec<(usize, usize)>>,
+    /// For each line, the half-edges pointing the same way as it, in order along it.
+    forward: Vec<Vec<usize>>,
+    /// A half-edge leaving each vertex.
+    vertex_edges: Vec<usize>,
+}
+
+/// A direction, as the direction of a line, reversed if the flag is set.
+type Heading = (usize, bool);
+
+// Construction //
+
+impl<N: Float> Arrangement<N> {
+    /// Build the arrangement of some lines.
+    ///
+    /// Lines with no direction (such as the line at infinity) are left out, as is any line which is the same as an
+    /// earlier one, in either orientation.  Their edges belong to the earlier line.  That's O(n²) vertices, edges
+    /// and faces for n lines, found in O(n² log n).
+    pub fn new(lines: &[Vector<N>]) -> Arrangement<N> {
+        let lines: Vec<Vector<N>> = lines.to_vec();
+        let mut used: Vec<usize> = Vec::with_capacity(lines.len());
+        let mut same_as: Vec<usize> = (0..lines.len()).collect();
+        for (index, line) in lines.iter().enumerate() {
+            if line.e1 == N::zero() && line.e2 == N::zero() {
+                continue;
+            }
+            match used.iter().find(|&&other| are_same(&lines[other], line)) {
+                Some(&other) => same_as[index] = other,
+                None => used.push(index),
+            }
+        }
+
+        let mut vertices: Vec<Bivector<N>> = Vec::new();
+        let mut meets: BTreeMap<(usize, usize), usize> = BTreeMap::new();
+        let mut along: Vec<Vec<(usize, usize)>> = vec![Vec::new(); lines.len()];
+        for &i in &used {
+            let mut crossing: Vec<usize> = used.iter()
+                .copied()
+                .filter(|&j| turn_between(&lines[i], &lines[j]) != Orientation::Collinear)
+                .collect();
+            crossing.sort_by(|&j, &k| compare_along(&lines[i], &lines[j], &lines[k]));
+
+            for group in crossing.chunk_by(|&j, &k| compare_along(&lines[i], &lines[j], &lines[k]) == Ordering::Equal) {
+                let mut through: Vec<usize> = group.to_vec();
+                through.push(i);
+                through.sort();
+                let key = (through[0], through[1]);
+                let vertex = *meets.entry(key).or_insert_with(|| {
+                    vertices.push(Point2d::from_bivector(&lines[key.0].wedge(&lines[key.1])).to_bivector());
+                    vertices.len() - 1
+                });
+                along[i].push((vertex, group[0]));
+            }
+        }
+
+        // The points at infinity, counterclockwise around the line at infinity.  Parallel lines share them.
+        let mut headings: Vec<Heading> = used.iter().flat_map(|&i| [(i, false), (i, true)]).collect();
+        headings.sort_by(|a, b| compare_headings(&lines, a, b));
+        let mut circle: Vec<usize> = Vec::new();
+        let mut at_infinity: BTreeMap<Heading, usize> = BTreeMap::new();
+        for (position, heading) in headings.iter().enumerate() {
+            if position == 0 || compare_headings(&lines, &headings[position - 1], heading) != Ordering::Equal {
+                let direction = Line2d::from_vector(&lines[heading.0]).direction();
+                vertices.push(if heading.1 { -direction } else { direction }.to_bivector());
+                circle.push(vertices.len() - 1);
+            }
+            at_infinity.insert(*heading, *circle.last().unwrap());
+        }
+
+        // Edges along each line, from the point at infinity behind it to the one ahead, then around the line at
+        // infinity.  Each half-edge's heading is kept for sorting them around their vertices.
+        let mut half_edges: Vec<HalfEdge> = Vec::new();
+        let mut half_edge_headings: Vec<Option<Heading>> = Vec::new();
+        let mut forward: Vec<Vec<usize>> = vec![Vec::new(); lines.len()];
+        for &i in &used {
+            let mut chain: Vec<usize> = vec![at_infinity[&(i, true)]];
+            chain.extend(along[i].iter().map(|&(vertex, _)| vertex));
+            chain.push(at_infinity[&(i, false)]);
+            for pair in chain.windows(2) {
+                forward[i].push(add_edge(&mut half_edges, pair[0], pair[1], Some(i)));
+                half_edge_headings.extend([Some((i, false)), Some((i, true))]);
+            }
+        }
+        let line_edges = half_edges.len();
+        if circle.len() > 1 {
+            for (position, &start) in circle.iter().enumerate() {
+                add_edge(&mut half_edges, start, circle[(position + 1) % circle.len()], None);
+                half_edge_headings.extend([None, None]);
+            }
+        }
+
+        // Sort the half-edges leaving each vertex counterclockwise, then link each half-edge coming in to the one
+        // clockwise of where it came from, which keeps the same face on the left.
+        let mut leaving: Vec<Vec<usize>> = vec![Vec::new(); vertices.len()];
+        for (index, half_edge) in half_edges.iter().enumerate() {
+            leaving[half_edge.origin].push(index);
+        }
+        for (vertex, out) in leaving.iter_mut().enumerate() {
+            if vertices[vertex].e12 != N::zero() {
+                out.sort_by(|&a, &b| compare_headings(&lines, &half_edge_headings[a].unwrap(), &half_edge_headings[b].unwrap()));
+                continue;
+            }
+            // At infinity, the line at infinity leaves counterclockwise first, then the lines coming in, from the
+            // counterclockwise side to the clockwise side, then the line at infinity leaving clockwise.
+            let (ahead, behind) = (out.iter().position(|&e| e >= line_edges && e % 2 == 0), out.iter().position(|&e| e >= line_edges && e % 2 == 1));
+            let (ahead, behind) = (out[ahead.unwrap()], out[behind.unwrap()]);
+            out.retain(|&e| e < line_edges);
+            out.sort_by(|&a, &b| {
+                let (line_a, line_b) = (half_edge_headings[a].unwrap(), half_edge_headings[b].unwrap());
+                let side = side_of_parallel(&lines[line_a.0], &lines[line_b.0]);
+                // Towards the counterclockwise side is towards the normal of `b`, or away from it if it's reversed.
+                if line_b.1 { side.reverse() } else { side }.reverse()
+            });
+            out.insert(0, ahead);
+            out.push(behind);
+        }
+        let vertex_edges: Vec<usize> = leaving.iter().map(|out| out[0]).collect();
+        for out in &leaving {
+            for (position, &e) in out.iter().enumerate() {
+                let incoming = half_edges[e].twin;
+                let next = out[(position + out.len() - 1) % out.len()];
+                half_edges[incoming].next = next;
+                half_edges[next].previous = incoming;
+            }
+        }
+
+        // Faces are the cycles of `next`, apart from the one outside the line at infinity.
+        let mut faces: Vec<Face> = Vec::new();
+        for start in 0..half_edges.len() {
+            if half_edges[start].face.is_some() || (start >= line_edges && start % 2 == 1) {
+                continue;
+            }
+            let mut bounded = true;
+            let mut e = start;
+            loop {
+                half_edges[e].face = Some(faces.len());
+                bounded = bounded && vertices[half_edges[e].origin].e12 != N::zero();
+                e = half_edges[e].next;
+                if e == start {
+                    break;
+                }
+            }
+            faces.push(Face { edge: Some(start), bounded });
+        }
+        if faces.is_empty() {
+            faces.push(Face { edge: None, bounded: false });
+        }
+
+        for (index, &same) in same_as.iter().enumerate() {
+            if same != index {
+                forward[index] = forward[same].clone();
+                along[index] = along[same].clone();
+            }
+        }
+        Arrangement { lines, vertices, half_edges, faces, meets, used, along, forward, vertex_edges }
+    }
+}
+
+// Accessors for arrangements //
+
+impl<N: Float> Arrangement<N> {
+    /// The lines, as they were given.
+    pub fn lines(&self) -> &[Vector<N>] {
+        &self.lines
+    }
+
+    /// The vertices.  Meets of lines are normalized, and points at infinity have unit length.
+    pub fn vertices(&self) -> &[Bivector<N>] {
+        &self.vertices
+    }
+
+    /// The half-edges, in pairs.  The twin of half-edge `i` is `i ^ 1`.
+    pub fn half_edges(&self) -> &[HalfEdge] {
+        &self.half_edges
+    }
+
+    /// The faces.
+    pub fn faces(&self) -> &[Face] {
+        &self.faces
+    }
+
+    /// The half-edges around a face, counterclockwise.
+    pub fn boundary(&self, face: usize) -> Vec<usize> {
+        let mut boundary: Vec<usize> = Vec::new();
+        if let Some(start) = self.faces[face].edge {
+            let mut e = start;
+            loop {
+                boundary.push(e);
+                e = self.half_edges[e].next;
+                if e == start {
+                    break;
+                }
+            }
+        }
+        boundary
+    }
+
+    /// The half-edges leaving a vertex, counterclockwise around it.
+    pub fn around(&self, vertex: usize) -> Vec<usize> {
+        let start = self.vertex_edges[vertex];
+        let mut around: Vec<usize> = Vec::new();
+        let mut e = start;
+        loop {
+            around.push(e);
+            e = self.half_edges[self.half_edges[e].previous].twin;
+            if e == start {
+                break;
+            }
+        }
+        around
+    }
+
+    /// The half-edges along a line, in order, pointing the same way as it.  A line which is the same as an earlier
+    /// one has the earlier one's edges, and a line with no direction has none.
+    pub fn edges_along(&self, line: usize) -> &[usize] {
+        &self.forward[line]
+    }
+}
+
+// Point location //
+
+impl<N: Float> Arrangement<N> {
+    /// Find the face, edge or vertex a point is on.
+    ///
+    /// This looks for the nearest line straight above the point (or below, if there's nothing above), then finds
+    /// where along it that is.  That's O(n log n) for n lines, and exact.
+    pub fn locate(&self, point: &Point2d<N>) -> Location {
+        let position = point.to_bivector();
+        let vertical = Vector { e0: -point.x, e1: N::one(), e2: N::zero() };
+        let horizontal = Vector { e0: -point.y, e1: N::zero(), e2: N::one() };
+
+        let through: Vec<usize> = self.used.iter()
+            .copied()
+            .filter(|&i| side_of_line(&self.lines[i], &position) == Ordering::Equal)
+            .collect();
+        match through.as_slice() {
+            [] => {},
+            [i] => {
+                let across = if self.crosses(*i, &vertical) { &vertical } else { &horizontal };
+                return Location::Edge(self.forward[*i][self.vertices_before(*i, across)]);
+            },
+            [first, second, ..] => return Location::Vertex(self.meets[&(*first, *second)]),
+        }
+
+        // Shoot a ray along the vertical line, unless every line is vertical.
+        let (ray, across) = if self.used.iter().any(|&i| self.crosses(i, &vertical)) {
+            (vertical, horizontal)
+        } else if self.used.iter().any(|&i| self.crosses(i, &horizontal)) {
+            (horizontal, vertical)
+        } else {
+            return Location::Face(0);
+        };
+        let crossing: Vec<usize> = self.used.iter().copied().filter(|&i| self.crosses(i, &ray)).collect();
+        let ahead: Vec<usize> = crossing.iter()
+            .copied()
+            .filter(|&i| compare_along(&ray, &self.lines[i], &across) == Ordering::Greater)
+            .collect();
+        let (candidates, reversed) = if ahead.is_empty() { (crossing, true) } else { (ahead, false) };
+        let nearest = |a: &usize, b: &usize| {
+            let order = compare_along(&ray, &self.lines[*a], &self.lines[*b]);
+            if reversed { order.reverse() } else { order }
+        };
+        let hit = *candidates.iter().min_by(|a, b| nearest(a, b)).unwrap();
+
+        let meeting: Vec<usize> = candidates.iter()
+            .copied()
+            .filter(|i| nearest(i, &hit) == Ordering::Equal)
+            .collect();
+        if meeting.len() > 1 {
+            // The ray hits a vertex, so the point is in the face around it which the ray came from.
+            let mut leaving = self.around(self.meets[&(meeting[0], meeting[1])]);
+            let lines: Vec<Vector<N>> = self.lines.iter().cloned().chain([ray]).collect();
+            let heading = |e: usize| (self.half_edges[e].line.unwrap(), e % 2 == 1);
+            leaving.sort_by(|&a, &b| compare_headings(&lines, &heading(a), &heading(b)));
+            let back = (self.lines.len(), !reversed);
+            let before = leaving.iter()
+                .rposition(|&e| compare_headings(&lines, &heading(e), &back) == Ordering::Less)
+                .unwrap_or(leaving.len() - 1);
+            return Location::Face(self.half_edges[leaving[before]].face.unwrap());
+        }
+
+        // The ray hits an edge.  Its face is on the left of the half-edge going the line's way, which is behind the
+        // ray's line if the line crosses it from right to left.
+        let edge = self.forward[hit][self.vertices_before(hit, &ray)];
+        let from_right = turn_between(&ray, &self.lines[hit]) == Orientation::CounterClockwise;
+        let edge = if from_right != reversed { edge } else { edge ^ 1 };
+        Location::Face(self.half_edges[edge].face.unwrap())
+    }
+
+    /// Whether a used line crosses another line at a finite point.
+    fn crosses(&self, i: usize, other: &Vector<N>) -> bool {
+        turn_between(&self.lines[i], other) != Orientation::Collinear
+    }
+
+    /// How many of the vertices along a line are before its meet with `across`.
+    fn vertices_before(&self, line: usize, across: &Vector<N>) -> usize {
+        self.along[line].partition_point(|&(_, other)| {
+            compare_along(&self.lines[line], &self.lines[other], across) == Ordering::Less
+        })
+    }
+}
+
+// Helpers //
+
+/// Add an edge from `start` to `end` as two half-edges, unlinked, and return the index of the first.
+fn add_edge(half_edges: &mut Vec<HalfEdge>, start: usize, end: usize, line: Option<usize>) -> usize {
+    let index = half_edges.len();
+    for (origin, twin) in [(start, index + 1), (end, index)] {
+        half_edges.push(HalfEdge { origin, twin, next: 0, previous: 0, face: None, line });
+    }
+    index
+}
+
+/// Where the meet of `line` with `a` is along `line`, compared to its meet with `b`.  Neither can be parallel to
+/// `line`.
+///
+/// Along a line, another line changes sign where it meets it, at a rate given by the turn between them.  So the
+/// meet with `a` is ahead of the meet with `b` when `b` is positive there, and `b` turns counterclockwise from
+/// `line`, or when both of those are the other way around.  The sign of `b` at the meet is the sign of
+/// `line ∧ a ∧ b` divided by the weight of the meet, which is the turn between `line` and `a`.
+fn compare_along<N: Float>(line: &Vector<N>, a: &Vector<N>, b: &Vector<N>) -> Ordering {
+    let signs = [orient_lines(line, a, b), turn_sign(line, a), turn_sign(line, b)];
+    signs.iter().fold(Ordering::Greater, |total, sign| match (total, sign) {
+        (_, Ordering::Equal) | (Ordering::Equal, _) => Ordering::Equal,
+        (total, Ordering::Greater) => total,
+        (total, Ordering::Less) => total.reverse(),
+    })
+}
+
+fn turn_sign<N: Float>(l1: &Vector<N>, l2: &Vector<N>) -> Ordering {
+    match turn_between(l1, l2) {
+        Orientation::CounterClockwise => Ordering::Greater,
+        Orientation::Clockwise => Ordering::Less,
+        Orientation::Collinear => Ordering::Equal,
+    }
+}
+
+/// Headings by angle, counterclockwise from the positive x axis.  A line `a x + b y + c` heads `(-b, a)`.
+fn compare_headings<N: Float>(lines: &[Vector<N>], a: &Heading, b: &Heading) -> Ordering {
+    let half = |(line, reversed): &Heading| {
+        let (x, y) = (-lines[*line].e2, lines[*line].e1);
+        let (x, y) = if *reversed { (-x, -y) } else { (x, y) };
+        y < N::zero() || (y == N::zero() && x < N::zero())
+    };
+    half(a).cmp(&half(b)).then_with(|| {
+        let turn = turn_sign(&lines[a.0], &lines[b.0]);
+        // Counterclockwise from `a` to `b` is later, unless one of them is reversed.
+        let turn = if a.1 != b.1 { turn.reverse() } else { turn };
+        turn.reverse()
+    })
+}
+
+/// Which side of `b` the parallel line `a` is on.  `Greater` is the side `b`'s normal points to.
+///
+/// The meet of `a` with the line through the origin perpendicular to it is a point on `a`, and perpendicular
+/// lines meet with a positive weight, so this is just the sign of their meet with `b`.
+fn side_of_parallel<N: Float>(a: &Vector<N>, b: &Vector<N>) -> Ordering {
+    let perpendicular = Vector { e0: N::zero(), e1: -a.e2, e2: a.e1 };
+    orient_lines(a, &perpendicular, b)
+}
+
+/// Whether two lines are the same line, in either orientation.
+fn are_same<N: Float>(a: &Vector<N>, b: &Vector<N>) -> bool {
+    turn_between(a, b) == Orientation::Collinear && side_of_parallel(a, b) == Ordering::Equal
+}
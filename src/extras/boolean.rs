@@ -0,0 +1,456 @@
+use alloc::vec::Vec;
+
+use core::cmp::Ordering;
+
+use num_traits::Float;
+
+use crate::{defs::bivector::Bivector, traits::OuterProduct};
+
+use super::{line2d::default_tolerance, ordering::compare, point2d::Point2d, polygon2d::{Polygon2d, Winding}, predicates::{orient2d, Orientation}, segment2d::Segment2d, transformations::RigidTransformation};
+
+// POLYGON BOOLEANS //
+// Every edge of each input is split wherever it crosses or touches an edge of the other input, so the two sets of
+// pieces only meet at their ends.  Each piece is then either inside the other input, outside it, or shared with it
+// (running the same way or the opposite way), and which pieces make up the result only depends on the operation.
+// The kept pieces are linked back up into loops, which are sorted into outer boundaries and holes.
+// Crossings are decided with the exact `predicates::orient2d`, and new vertices are the meet of the two edges' lines.
+// Results get fed back in (carving one shape after another), so vertices and edges which rounding has left a hair
+// apart are snapped together first, within the default tolerance.
+// This is O(n²) in the number of edges.  Requires the `alloc` feature.
+
+/// A polygon with holes cut out of it.  The outer boundary is counterclockwise and the holes are clockwise, so the
+/// inside is always to the left of the edges.  Requires the `alloc` feature.
+///
+/// The boundary and the holes should be simple (not cross themselves), the holes should be inside the boundary, and
+/// none of them should overlap.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PolygonWithHoles<N: Float> {
+    exterior: Polygon2d<N>,
+    holes: Vec<Polygon2d<N>>,
+}
+
+/// Which boolean operation to do.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BooleanOp {
+    /// Everything in either input.
+    Union,
+    /// Everything in both inputs.
+    Intersection,
+    /// Everything in the first input, but not the second.
+    Difference,
+    /// Everything in exactly one of the inputs.
+    Xor,
+}
+
+// Constructors and accessors for polygons with holes //
+
+impl<N: Float> PolygonWithHoles<N> {
+    /// Create a polygon with holes.  The boundary and holes can go either way, they're turned to face the right way.
+    pub fn new(exterior: Polygon2d<N>, holes: Vec<Polygon2d<N>>) -> PolygonWithHoles<N> {
+        PolygonWithHoles {
+            exterior: oriented(exterior, Winding::CounterClockwise),
+            holes: holes.into_iter().map(|hole| oriented(hole, Winding::Clockwise)).collect(),
+        }
+    }
+
+    /// The outer boundary, counterclockwise.
+    pub fn exterior(&self) -> &Polygon2d<N> {
+        &self.exterior
+    }
+
+    /// The holes, each clockwise.
+    pub fn holes(&self) -> &[Polygon2d<N>] {
+        &self.holes
+    }
+
+    /// The area inside the boundary, less the area of the holes.
+    pub fn area(&self) -> N {
+        self.rings().fold(N::zero(), |area, ring| area + ring.signed_area())
+    }
+
+    /// Whether a point is inside, but not in a hole.  Points on an edge might go either way.
+    pub fn contains(&self, point: &Point2d<N>) -> bool {
+        self.winding_number(point) > 0
+    }
+
+    /// The boundary then the holes.
+    fn rings(&self) -> impl Iterator<Item = &Polygon2d<N>> + '_ {
+        core::iter::once(&self.exterior).chain(self.holes.iter())
+    }
+
+    /// 1 inside, 0 outside or in a hole, since the holes go the other way.
+    fn winding_number(&self, point: &Point2d<N>) -> i32 {
+        self.rings().map(|ring| ring.winding_number(point)).sum()
+    }
+}
+
+impl<N: Float> From<Polygon2d<N>> for PolygonWithHoles<N> {
+    fn from(polygon: Polygon2d<N>) -> PolygonWithHoles<N> {
+        PolygonWithHoles::new(polygon, Vec::new())
+    }
+}
+
+/// Anything which can transform a bivector can transform a polygon with holes.
+impl<N: Float, T: RigidTransformation<Bivector<N>>> RigidTransformation<PolygonWithHoles<N>> for T {
+    fn apply(&self, target: &PolygonWithHoles<N>) -> PolygonWithHoles<N> {
+        let transform = |ring: &Polygon2d<N>| Polygon2d::new(
+            ring.vertices()
+                .iter()
+                .map(|vertex| Point2d::from_bivector(&self.apply(&vertex.to_bivector())))
+                .collect()
+        );
+        PolygonWithHoles {
+            exterior: transform(&target.exterior),
+            holes: target.holes.iter().map(transform).collect(),
+        }
+    }
+}
+
+// Operations //
+
+/// The union of two sets of polygons.  See `boolean`.
+pub fn union<N: Float>(a: &[PolygonWithHoles<N>], b: &[PolygonWithHoles<N>]) -> Vec<PolygonWithHoles<N>> {
+    boolean(BooleanOp::Union, a, b)
+}
+
+/// The intersection of two sets of polygons.  See `boolean`.
+pub fn intersection<N: Float>(a: &[PolygonWithHoles<N>], b: &[PolygonWithHoles<N>]) -> Vec<PolygonWithHoles<N>> {
+    boolean(BooleanOp::Intersection, a, b)
+}
+
+/// The first set of polygons with the second cut out of it.  See `boolean`.
+pub fn difference<N: Float>(a: &[PolygonWithHoles<N>], b: &[PolygonWithHoles<N>]) -> Vec<PolygonWithHoles<N>> {
+    boolean(BooleanOp::Difference, a, b)
+}
+
+/// Everything in exactly one of two sets of polygons.  See `boolean`.
+pub fn xor<N: Float>(a: &[PolygonWithHoles<N>], b: &[PolygonWithHoles<N>]) -> Vec<PolygonWithHoles<N>> {
+    boolean(BooleanOp::Xor, a, b)
+}
+
+/// A boolean operation on two sets of polygons.  The polygons within each set shouldn't overlap or share edges.
+/// Vertices within the default tolerance of each other are merged first.
+///
+/// The result is a set of polygons which don't overlap.  Parts which only touch at a corner are separate polygons,
+/// and vertices in the middle of a straight edge are dropped.  Parts with no area (such as where the inputs only
+/// share an edge) are left out.
+pub fn boolean<N: Float>(op: BooleanOp, a: &[PolygonWithHoles<N>], b: &[PolygonWithHoles<N>]) -> Vec<PolygonWithHoles<N>> {
+    // Both inputs' edges together, since a hole can touch its own boundary, and the other input needs to be split
+    // there too.
+    let mut vertices = Vec::new();
+    let mut all = edges(a, &mut vertices);
+    let a_len = all.len();
+    all.extend(edges(b, &mut vertices));
+    split(&mut all);
+    let a_pieces = pieces(&all[..a_len]);
+    let b_pieces = pieces(&all[a_len..]);
+
+    let a_shared = Shared::new(&a_pieces);
+    let b_shared = Shared::new(&b_pieces);
+
+    let mut kept: Vec<(Point2d<N>, Point2d<N>)> = Vec::new();
+    for &(start, end) in a_pieces.iter() {
+        let place = classify(start, end, b, &b_shared);
+        match (op, place) {
+            (BooleanOp::Union, Place::Outside | Place::Same)
+            | (BooleanOp::Intersection, Place::Inside | Place::Same)
+            | (BooleanOp::Difference, Place::Outside | Place::Opposite)
+            | (BooleanOp::Xor, Place::Outside) => kept.push((start, end)),
+            (BooleanOp::Xor, Place::Inside) => kept.push((end, start)),
+            _ => {},
+        }
+    }
+    for &(start, end) in b_pieces.iter() {
+        // Shared pieces were already decided by the first input's copy.
+        let place = classify(start, end, a, &a_shared);
+        match (op, place) {
+            (BooleanOp::Union, Place::Outside)
+            | (BooleanOp::Intersection, Place::Inside)
+            | (BooleanOp::Xor, Place::Outside) => kept.push((start, end)),
+            (BooleanOp::Difference | BooleanOp::Xor, Place::Inside) => kept.push((end, start)),
+            _ => {},
+        }
+    }
+
+    assemble(trace(kept))
+}
+
+// Splitting //
+
+/// An edge, and the points it has to be split at.
+struct Edge<N: Float> {
+    start: Point2d<N>,
+    end: Point2d<N>,
+    splits: Vec<Point2d<N>>,
+}
+
+/// The edges of every polygon, with their ends snapped onto the vertices seen so far.  See `snap`.
+fn edges<N: Float>(polygons: &[PolygonWithHoles<N>], vertices: &mut Vec<Point2d<N>>) -> Vec<Edge<N>> {
+    let tolerance = default_tolerance::<N>();
+    let mut edges = Vec::new();
+    for edge in polygons.iter().flat_map(|polygon| polygon.rings()).flat_map(|ring| ring.edges()) {
+        let start = snap(vertices, edge.start, tolerance);
+        let end = snap(vertices, edge.end, tolerance);
+        if start != end {
+            edges.push(Edge { start, end, splits: Vec::new() });
+        }
+    }
+    edges
+}
+
+/// The first vertex within the tolerance of a point, or the point itself as a new vertex.  Vertices left by an
+/// earlier operation can be rounded to just next to each other, and splitting at both would leave a piece too small
+/// to tell which side of anything it's on.
+fn snap<N: Float>(vertices: &mut Vec<Point2d<N>>, point: Point2d<N>, tolerance: N) -> Point2d<N> {
+    match vertices.iter().find(|vertex| (**vertex - point).length() <= tolerance) {
+        Some(vertex) => *vertex,
+        None => {
+            vertices.push(point);
+            point
+        },
+    }
+}
+
+/// Find where each pair of edges touches, and mark those points on both.
+///
+/// An end which touches the other edge splits it at that end, exactly, so the pieces on each side agree on it.  Ends
+/// which come within the default tolerance of the other edge count as touching too, since they're usually vertices
+/// from an earlier operation which rounding has moved just off an edge.  Otherwise, edges which cross (by the exact
+/// `orient2d`) are both split at the meet of their lines.
+fn split<N: Float>(edges: &mut [Edge<N>]) {
+    let tolerance = default_tolerance::<N>();
+    for i in 1..edges.len() {
+        let (before, after) = edges.split_at_mut(i);
+        let eb = &mut after[0];
+        for ea in before.iter_mut() {
+            let (a0, a1, b0, b1) = (ea.start, ea.end, eb.start, eb.end);
+
+            let mut touching = false;
+            for end in [b0, b1] {
+                if touches(&a0, &a1, &end, tolerance) {
+                    touching = true;
+                    if end != a0 && end != a1 {
+                        ea.splits.push(end);
+                    }
+                }
+            }
+            for end in [a0, a1] {
+                if touches(&b0, &b1, &end, tolerance) {
+                    touching = true;
+                    if end != b0 && end != b1 {
+                        eb.splits.push(end);
+                    }
+                }
+            }
+            if touching {
+                continue;
+            }
+
+            let o1 = orient2d(&a0, &a1, &b0);
+            let o2 = orient2d(&a0, &a1, &b1);
+            let o3 = orient2d(&b0, &b1, &a0);
+            let o4 = orient2d(&b0, &b1, &a1);
+            let collinear = [o1, o2, o3, o4].contains(&Orientation::Collinear);
+            if !collinear && o1 != o2 && o3 != o4 {
+                let meet = Point2d::line_between_points(a0, a1).wedge(&Point2d::line_between_points(b0, b1));
+                let point = Point2d::from_bivector(&meet);
+                ea.splits.push(point);
+                eb.splits.push(point);
+            }
+        }
+    }
+}
+
+/// Whether a point is one of an edge's ends, or on the edge (within a tolerance).
+fn touches<N: Float>(start: &Point2d<N>, end: &Point2d<N>, point: &Point2d<N>, tolerance: N) -> bool {
+    if point == start || point == end {
+        return true;
+    }
+    let closest = Segment2d::new(*start, *end).closest_point(point);
+    (closest - *point).length() <= tolerance
+}
+
+/// Cut each edge at its split points, in order along it.
+fn pieces<N: Float>(edges: &[Edge<N>]) -> Vec<(Point2d<N>, Point2d<N>)> {
+    let mut pieces = Vec::with_capacity(edges.len());
+    for edge in edges {
+        let direction = edge.end - edge.start;
+        let along = |point: &Point2d<N>| (*point - edge.start).dot(&direction);
+        let mut points = edge.splits.clone();
+        points.sort_by(|p, q| along(p).partial_cmp(&along(q)).unwrap_or(Ordering::Equal));
+        points.dedup();
+
+        let mut start = edge.start;
+        for point in points.into_iter().chain(core::iter::once(edge.end)) {
+            if point != start {
+                pieces.push((start, point));
+                start = point;
+            }
+        }
+    }
+    pieces
+}
+
+// Classification //
+
+/// Where a piece of one input is, compared to the other input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Place {
+    Inside,
+    Outside,
+    /// On an edge of the other input, going the same way.
+    Same,
+    /// On an edge of the other input, going the opposite way.
+    Opposite,
+}
+
+fn classify<N: Float>(start: Point2d<N>, end: Point2d<N>, other: &[PolygonWithHoles<N>], shared: &Shared<N>) -> Place {
+    if let Some(forwards) = shared.find(start, end) {
+        return if forwards { Place::Same } else { Place::Opposite };
+    }
+    let middle = start + (end - start) * N::from(0.5).unwrap();
+    let winding: i32 = other.iter().map(|polygon| polygon.winding_number(&middle)).sum();
+    if winding > 0 { Place::Inside } else { Place::Outside }
+}
+
+/// One input's pieces, sorted by their ends, to find the pieces shared with the other input.
+struct Shared<N: Float> {
+    /// Each piece's ends, lowest first, and whether the piece goes from the lowest to the highest.
+    keys: Vec<(Point2d<N>, Point2d<N>, bool)>,
+}
+
+impl<N: Float> Shared<N> {
+    fn new(pieces: &[(Point2d<N>, Point2d<N>)]) -> Shared<N> {
+        let mut keys: Vec<_> = pieces
+            .iter()
+            .map(|&(start, end)| match compare(&start, &end) {
+                Ordering::Greater => (end, start, false),
+                _ => (start, end, true),
+            })
+            .collect();
+        keys.sort_by(|x, y| compare(&x.0, &y.0).then(compare(&x.1, &y.1)));
+        Shared { keys }
+    }
+
+    /// Whether there's a piece between these points, and if so, whether it goes the same way.
+    fn find(&self, start: Point2d<N>, end: Point2d<N>) -> Option<bool> {
+        let (low, high, forwards) = match compare(&start, &end) {
+            Ordering::Greater => (end, start, false),
+            _ => (start, end, true),
+        };
+        self.keys
+            .binary_search_by(|probe| compare(&probe.0, &low).then(compare(&probe.1, &high)))
+            .ok()
+            .map(|i| self.keys[i].2 == forwards)
+    }
+}
+
+// Assembly //
+
+/// Link pieces into loops.  Where more than one piece leaves a vertex, the one turning furthest left is taken, so
+/// the inside of each loop stays as small as possible, and parts which only touch at a corner come out separate.
+/// Pieces which can't be linked into a loop (which only happens when rounding has broken something) are dropped.
+fn trace<N: Float>(mut pieces: Vec<(Point2d<N>, Point2d<N>)>) -> Vec<Polygon2d<N>> {
+    pieces.sort_by(|x, y| compare(&x.0, &y.0).then(compare(&x.1, &y.1)));
+    let mut used = alloc::vec![false; pieces.len()];
+    let mut loops = Vec::new();
+
+    for first in 0..pieces.len() {
+        if used[first] {
+            continue;
+        }
+        used[first] = true;
+        let mut vertices = alloc::vec![pieces[first].0];
+        let mut current = first;
+        loop {
+            let (from, at) = pieces[current];
+            // Every piece leaving this vertex which is still free, and the first piece if this is where it started.
+            let leaving = pieces.partition_point(|piece| compare(&piece.0, &at) == Ordering::Less);
+            let next = (leaving..pieces.len())
+                .take_while(|&i| pieces[i].0 == at)
+                .filter(|&i| !used[i] || i == first)
+                .min_by(|&i, &j| {
+                    left_turn(from, at, pieces[i].1)
+                        .partial_cmp(&left_turn(from, at, pieces[j].1))
+                        .unwrap_or(Ordering::Equal)
+                });
+            match next {
+                Some(next) if next == first => {
+                    loops.push(Polygon2d::new(vertices));
+                    break;
+                },
+                Some(next) => {
+                    used[next] = true;
+                    vertices.push(at);
+                    current = next;
+                },
+                None => break,
+            }
+        }
+    }
+    loops
+}
+
+/// How far clockwise `to` is from the way back to `from`, as seen from `at`, in (0, 2π].  Smaller is further left.
+fn left_turn<N: Float>(from: Point2d<N>, at: Point2d<N>, to: Point2d<N>) -> N {
+    let back = from - at;
+    let out = to - at;
+    // The angle from `out` to `back`, counterclockwise.
+    let angle = (out.x * back.y - out.y * back.x).atan2(out.dot(&back));
+    let tau = N::from(2.0).unwrap() * N::from(core::f64::consts::PI).unwrap();
+    if angle <= N::zero() { angle + tau } else { angle }
+}
+
+/// Sort loops into counterclockwise boundaries and clockwise holes, and put each hole in the smallest boundary
+/// around it.
+fn assemble<N: Float>(loops: Vec<Polygon2d<N>>) -> Vec<PolygonWithHoles<N>> {
+    let mut exteriors: Vec<PolygonWithHoles<N>> = Vec::new();
+    let mut holes: Vec<Polygon2d<N>> = Vec::new();
+    for ring in loops {
+        let ring = simplified(ring);
+        match ring.winding() {
+            Winding::CounterClockwise => exteriors.push(PolygonWithHoles { exterior: ring, holes: Vec::new() }),
+            Winding::Clockwise => holes.push(ring),
+            Winding::Degenerate => {},
+        }
+    }
+
+    for hole in holes {
+        // The middle of an edge, since a corner could be touching the boundary.
+        let (start, end) = (hole.vertices()[0], hole.vertices()[1]);
+        let middle = start + (end - start) * N::from(0.5).unwrap();
+        let around = exteriors
+            .iter_mut()
+            .filter(|polygon| polygon.exterior.winding_number(&middle) != 0)
+            .min_by(|p, q| p.exterior.area().partial_cmp(&q.exterior.area()).unwrap_or(Ordering::Equal));
+        if let Some(polygon) = around {
+            polygon.holes.push(hole);
+        }
+    }
+    exteriors
+}
+
+/// Drop vertices in the middle of a straight edge, exactly.  Dropping one can straighten out its neighbours, so this
+/// goes around until nothing changes.
+fn simplified<N: Float>(ring: Polygon2d<N>) -> Polygon2d<N> {
+    let mut vertices = ring.into_vertices();
+    loop {
+        let n = vertices.len();
+        let straight = (0..n).find(|&i| {
+            orient2d(&vertices[(i + n - 1) % n], &vertices[i], &vertices[(i + 1) % n]) == Orientation::Collinear
+        });
+        match straight {
+            Some(i) if n > 3 => { vertices.remove(i); },
+            _ => return Polygon2d::new(vertices),
+        }
+    }
+}
+
+/// A polygon turned to go the given way, unless it has no area.
+fn oriented<N: Float>(polygon: Polygon2d<N>, winding: Winding) -> Polygon2d<N> {
+    match polygon.winding() {
+        Winding::Degenerate => polygon,
+        current if current == winding => polygon,
+        _ => polygon.reversed(),
+    }
+}
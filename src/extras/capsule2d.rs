@@ -0,0 +1,44 @@
+use num_traits::Float;
+
+use crate::defs::bivector::Bivector;
+
+use super::{point2d::Point2d, segment2d::Segment2d, transformations::RigidTransformation};
+
+/// A capsule: every point within `radius` of a segment.  That's a rectangle with a half circle on each end.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Capsule2d<N: Float> {
+    pub segment: Segment2d<N>,
+    pub radius: N,
+}
+
+// Constructors //
+
+impl<N: Float> Capsule2d<N> {
+    /// Create a new capsule around the segment from `start` to `end`.
+    /// This is a `const fn`, so it can be used to build `const` and `static` capsules.
+    pub const fn new(start: Point2d<N>, end: Point2d<N>, radius: N) -> Capsule2d<N> {
+        Capsule2d { segment: Segment2d::new(start, end), radius }
+    }
+}
+
+// Containment //
+
+impl<N: Float> Capsule2d<N> {
+    /// Whether a point is inside this capsule, or on its edge.
+    pub fn contains(&self, point: &Point2d<N>) -> bool {
+        self.segment.closest_point(point).distance_to(point) <= self.radius
+    }
+}
+
+/// Anything which can transform a bivector can transform a capsule.
+impl<N: Float, T: RigidTransformation<Bivector<N>>> RigidTransformation<Capsule2d<N>> for T {
+    fn apply(&self, target: &Capsule2d<N>) -> Capsule2d<N> {
+        Capsule2d {
+            segment: Segment2d {
+                start: Point2d::from_bivector(&self.apply(&target.segment.start.to_bivector())),
+                end: Point2d::from_bivector(&self.apply(&target.segment.end.to_bivector())),
+            },
+            radius: target.radius,
+        }
+    }
+}
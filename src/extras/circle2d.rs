@@ -0,0 +1,179 @@
+use num_traits::Float;
+
+use crate::defs::{vector::Vector, bivector::Bivector};
+
+use super::{line2d::{Line2d, default_tolerance}, point2d::Point2d, segment2d::Segment2d, transformations::RigidTransformation};
+
+/// A circle, from its center and radius.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Circle2d<N: Float> {
+    pub center: Point2d<N>,
+    pub radius: N,
+}
+
+/// The result of intersecting a circle (or arc) with something.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CircleIntersection<N: Float> {
+    /// They don't touch.
+    None,
+    /// They touch at a single point.  Tangent, or only one crossing is within a segment or arc.
+    One(Point2d<N>),
+    /// They cross at two points.
+    Two(Point2d<N>, Point2d<N>),
+    /// They're the same circle, so there's no finite set of points.
+    Coincident,
+}
+
+impl<N: Float> CircleIntersection<N> {
+    /// Keep only the points which pass `predicate`.  `Coincident` is left alone.
+    pub fn filter<F: Fn(&Point2d<N>) -> bool>(self, predicate: F) -> CircleIntersection<N> {
+        match self {
+            CircleIntersection::One(p) if !predicate(&p) => CircleIntersection::None,
+            CircleIntersection::Two(p1, p2) => match (predicate(&p1), predicate(&p2)) {
+                (true, true) => CircleIntersection::Two(p1, p2),
+                (true, false) => CircleIntersection::One(p1),
+                (false, true) => CircleIntersection::One(p2),
+                (false, false) => CircleIntersection::None,
+            },
+            other => other,
+        }
+    }
+}
+
+// Constructors //
+
+impl<N: Float> Circle2d<N> {
+    /// Create a new circle.
+    /// This is a `const fn`, so it can be used to build `const` and `static` circles.
+    pub const fn new(center: Point2d<N>, radius: N) -> Circle2d<N> {
+        Circle2d { center, radius }
+    }
+}
+
+// Containment //
+
+impl<N: Float> Circle2d<N> {
+    /// Whether a point is inside this circle, or on its edge.
+    pub fn contains(&self, point: &Point2d<N>) -> bool {
+        self.center.distance_to(point) <= self.radius
+    }
+
+    /// Whether another circle is entirely inside this one.
+    pub fn contains_circle(&self, other: &Circle2d<N>) -> bool {
+        self.center.distance_to(&other.center) + other.radius <= self.radius
+    }
+}
+
+// Intersections //
+
+impl<N: Float> Circle2d<N> {
+    /// Intersect this circle with a line, with the default tolerance.  See `intersect_line_with_tolerance`.
+    pub fn intersect_line(&self, line: &Line2d<N>) -> CircleIntersection<N> {
+        self.intersect_line_with_tolerance(line, default_tolerance())
+    }
+
+    /// Intersect this circle with a line.  The foot of the perpendicular from the center is the midpoint of the
+    /// crossings, which are ordered along the line's direction.
+    /// A line within `tolerance` of touching the circle is tangent.
+    pub fn intersect_line_with_tolerance(&self, line: &Line2d<N>, tolerance: N) -> CircleIntersection<N> {
+        let distance = line.to_vector().distance_to_point(&self.center.to_bivector());
+        let foot = self.center.project_onto(&line.to_vector());
+        if distance.abs() > self.radius + tolerance {
+            return CircleIntersection::None;
+        }
+        if (distance.abs() - self.radius).abs() <= tolerance {
+            return CircleIntersection::One(foot);
+        }
+        let half = (self.radius * self.radius - distance * distance).sqrt();
+        let direction = line.direction();
+        CircleIntersection::Two(foot - direction * half, foot + direction * half)
+    }
+
+    /// Intersect this circle with a segment, with the default tolerance.  See `intersect_segment_with_tolerance`.
+    pub fn intersect_segment(&self, segment: &Segment2d<N>) -> CircleIntersection<N> {
+        self.intersect_segment_with_tolerance(segment, default_tolerance())
+    }
+
+    /// Intersect this circle with a segment.  The crossings with its line, limited to the segment, in order from
+    /// the segment's start.
+    pub fn intersect_segment_with_tolerance(&self, segment: &Segment2d<N>, tolerance: N) -> CircleIntersection<N> {
+        self.intersect_line_with_tolerance(&segment.line(), tolerance)
+            .filter(|p| segment.closest_point(p).distance_to(p) <= tolerance)
+    }
+
+    /// The radical line of two circles.  That is, the line of points with equal power with respect to both.
+    /// For crossing circles, this is the line through both crossings.  Its normal points from this circle's center
+    /// towards the other's.
+    pub fn radical_line(&self, other: &Circle2d<N>) -> Vector<N> {
+        let two = N::one() + N::one();
+        let (c1, c2) = (self.center, other.center);
+        let power1 = c1.x * c1.x + c1.y * c1.y - self.radius * self.radius;
+        let power2 = c2.x * c2.x + c2.y * c2.y - other.radius * other.radius;
+        Vector {
+            e0: power1 - power2,
+            e1: two * (c2.x - c1.x),
+            e2: two * (c2.y - c1.y),
+        }
+    }
+
+    /// Intersect two circles, with the default tolerance.  See `intersect_circle_with_tolerance`.
+    pub fn intersect_circle(&self, other: &Circle2d<N>) -> CircleIntersection<N> {
+        self.intersect_circle_with_tolerance(other, default_tolerance())
+    }
+
+    /// Intersect two circles, by intersecting this one with their radical line.  The crossings are ordered along
+    /// the radical line.  Circles within `tolerance` of each other in center and radius are coincident.
+    pub fn intersect_circle_with_tolerance(&self, other: &Circle2d<N>, tolerance: N) -> CircleIntersection<N> {
+        if self.center.distance_to(&other.center) <= tolerance {
+            return if (self.radius - other.radius).abs() <= tolerance {
+                CircleIntersection::Coincident
+            } else {
+                CircleIntersection::None
+            };
+        }
+        self.intersect_line_with_tolerance(&Line2d::from_vector(&self.radical_line(other)), tolerance)
+    }
+
+    /// The two tangent lines to this circle through an external point, with the default tolerance.
+    /// See `tangents_from_with_tolerance`.
+    pub fn tangents_from(&self, point: &Point2d<N>) -> Option<(Line2d<N>, Line2d<N>)> {
+        self.tangents_from_with_tolerance(point, default_tolerance())
+    }
+
+    /// The two tangent lines to this circle through an external point, pointing from the point towards the circle.
+    /// The tangent points are where the circle crosses the polar line of the point.  The first line has the
+    /// circle on its right (the side its normal points to), and the second has it on its left.
+    /// A point inside the circle has no tangents.  For a point within `tolerance` of the edge, both are the same line.
+    pub fn tangents_from_with_tolerance(&self, point: &Point2d<N>, tolerance: N) -> Option<(Line2d<N>, Line2d<N>)> {
+        let offset = *point - self.center;
+        let distance = offset.length();
+        if distance < self.radius - tolerance {
+            return None;
+        }
+        if distance <= self.radius + tolerance {
+            let tangent = Line2d::from_point_direction(*point, offset.perpendicular());
+            return Some((tangent, tangent));
+        }
+
+        // The polar line is (X - C)·(P - C) = r².
+        let polar = Vector {
+            e0: -(offset.x * self.center.x + offset.y * self.center.y) - self.radius * self.radius,
+            e1: offset.x,
+            e2: offset.y,
+        };
+        match self.intersect_line_with_tolerance(&Line2d::from_vector(&polar), N::zero()) {
+            CircleIntersection::Two(t1, t2) => Some((Line2d::from_points(*point, t1), Line2d::from_points(*point, t2))),
+            _ => None,
+        }
+    }
+}
+
+/// Anything which can transform a bivector can transform a circle.
+impl<N: Float, T: RigidTransformation<Bivector<N>>> RigidTransformation<Circle2d<N>> for T {
+    fn apply(&self, target: &Circle2d<N>) -> Circle2d<N> {
+        Circle2d {
+            center: Point2d::from_bivector(&self.apply(&target.center.to_bivector())),
+            radius: target.radius,
+        }
+    }
+}
@@ -0,0 +1,132 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use core::cmp::Ordering;
+
+use num_traits::Float;
+
+use crate::{defs::vector::Vector, traits::OuterProduct};
+
+use super::{point2d::Point2d, predicates::side_of_line, segment2d::Segment2d};
+
+#[cfg(feature = "alloc")]
+use super::polygon2d::Polygon2d;
+
+// HALF-PLANE CLIPPING //
+// An oriented line is a half-plane: the side to its left, where `l ∧ P <= 0`.  This is the inside of a
+// counterclockwise polygon's edges, so a convex polygon is the intersection of its edge lines' half-planes.
+// Which side a point is on comes from the exact `predicates::side_of_line`, and new vertices are the meet (wedge) of
+// the clipping line with the clipped edge.
+// Points exactly on a clipping line are kept.
+
+/// Clip a polygon to the half-plane left of `line`, with Sutherland–Hodgman.  Requires the `alloc` feature.
+///
+/// Clipping a convex polygon gives a convex polygon.  A concave polygon stays in one piece, possibly with edges
+/// running along the line between its parts.  If less than three distinct vertices are left (such as when the
+/// polygon only touches the line), the result is empty.
+#[cfg(feature = "alloc")]
+pub fn clip_polygon<N: Float>(polygon: &Polygon2d<N>, line: &Vector<N>) -> Polygon2d<N> {
+    let vertices = polygon.vertices();
+    let mut clipped: Vec<Point2d<N>> = Vec::with_capacity(vertices.len() + 1);
+
+    for (i, end) in vertices.iter().enumerate() {
+        let start = &vertices[(i + vertices.len() - 1) % vertices.len()];
+        let start_side = side_of_line(line, &start.to_bivector());
+        let end_side = side_of_line(line, &end.to_bivector());
+
+        if end_side != Ordering::Greater {
+            if start_side == Ordering::Greater && end_side == Ordering::Less {
+                clipped.push(crossing(line, start, end));
+            }
+            clipped.push(*end);
+        } else if start_side == Ordering::Less {
+            clipped.push(crossing(line, start, end));
+        }
+    }
+
+    clipped.dedup();
+    while clipped.len() > 1 && clipped.first() == clipped.last() {
+        clipped.pop();
+    }
+    if clipped.len() < 3 {
+        clipped.clear();
+    }
+    Polygon2d::new(clipped)
+}
+
+/// Clip a polygon to the intersection of the half-planes left of each line.  See `clip_polygon`.
+/// Requires the `alloc` feature.
+#[cfg(feature = "alloc")]
+pub fn clip_polygon_to_all<N: Float>(polygon: &Polygon2d<N>, lines: &[Vector<N>]) -> Polygon2d<N> {
+    let mut clipped = polygon.clone();
+    for line in lines {
+        if clipped.is_empty() {
+            break;
+        }
+        clipped = clip_polygon(&clipped, line);
+    }
+    clipped
+}
+
+/// Clip a segment to the half-plane left of `line`.  See `clip_segment_to_all`.
+pub fn clip_segment<N: Float>(segment: &Segment2d<N>, line: &Vector<N>) -> Option<Segment2d<N>> {
+    clip_segment_to_all(segment, core::slice::from_ref(line))
+}
+
+/// Clip a segment to the intersection of the half-planes left of each line, with Liang–Barsky.  Returns `None` if
+/// nothing is left.  The clipped segment keeps the original's direction.
+///
+/// The meet of each line with the segment's ends changes linearly along the segment, so each line cuts the segment
+/// at one parameter, and the part that's left is between the last entry and the first exit.  The new ends are the
+/// meets of those lines with the segment's line.
+/// A segment which only touches the region at a single point is clipped away.
+pub fn clip_segment_to_all<N: Float>(segment: &Segment2d<N>, lines: &[Vector<N>]) -> Option<Segment2d<N>> {
+    let (mut enter, mut enter_line) = (N::zero(), None);
+    let (mut exit, mut exit_line) = (N::one(), None);
+
+    for line in lines {
+        let start_side = side_of_line(line, &segment.start.to_bivector());
+        let end_side = side_of_line(line, &segment.end.to_bivector());
+        match (start_side, end_side) {
+            (Ordering::Greater, Ordering::Greater)
+            | (Ordering::Greater, Ordering::Equal)
+            | (Ordering::Equal, Ordering::Greater) => return None,
+            (Ordering::Greater, Ordering::Less) => {
+                let t = parameter(line, segment);
+                if t > enter {
+                    enter = t;
+                    enter_line = Some(line);
+                }
+            },
+            (Ordering::Less, Ordering::Greater) => {
+                let t = parameter(line, segment);
+                if t < exit {
+                    exit = t;
+                    exit_line = Some(line);
+                }
+            },
+            _ => {},
+        }
+        if enter >= exit {
+            return None;
+        }
+    }
+
+    let start = enter_line.map_or(segment.start, |line| crossing(line, &segment.start, &segment.end));
+    let end = exit_line.map_or(segment.end, |line| crossing(line, &segment.start, &segment.end));
+    Some(Segment2d::new(start, end))
+}
+
+/// The parameter along a segment where it crosses a line.  Clamped, in case rounding disagrees with the exact sides.
+fn parameter<N: Float>(line: &Vector<N>, segment: &Segment2d<N>) -> N {
+    let start = line.wedge(&segment.start.to_bivector()).e012;
+    let end = line.wedge(&segment.end.to_bivector()).e012;
+    (start / (start - end))
+        .max(N::zero())
+        .min(N::one())
+}
+
+/// Where the edge from `start` to `end` crosses `line`.  The meet of the line with the join of the two points.
+fn crossing<N: Float>(line: &Vector<N>, start: &Point2d<N>, end: &Point2d<N>) -> Point2d<N> {
+    Point2d::from_bivector(&line.wedge(&Point2d::line_between_points(*start, *end)))
+}
@@ -0,0 +1,600 @@
+use alloc::{collections::VecDeque, vec::Vec};
+
+use core::{cmp::Ordering, fmt::Display};
+
+use num_traits::Float;
+
+use super::{boolean::PolygonWithHoles, ordering::sort_points, point2d::Point2d, predicates::{incircle, orient2d, Orientation}};
+
+// DELAUNAY TRIANGULATION //
+// The points are sorted left to right and swept in that order.  Each one is outside the triangles so far, so it's
+// joined to every hull edge it can see.  That's a valid triangulation, but not a Delaunay one, so edges are then
+// flipped until every edge passes the incircle test (Lawson's algorithm).
+// Constrained edges are added afterwards, by flipping away the edges they cross (Sloan's algorithm), and the incircle
+// flips are run again, never flipping a constrained edge.
+// Turns and circles are decided with the exact `predicates::orient2d` and `predicates::incircle`.
+// Requires the `alloc` feature.
+
+/// Triangles covering a set of points, as indices into them.  Requires the `alloc` feature.
+///
+/// Each triangle is counterclockwise.  Edge `i` of a triangle is the one opposite its vertex `i`, going from vertex
+/// `i + 1` to vertex `i + 2`, and the neighbour across it is `neighbours()[triangle][i]`, if there is one.
+/// Duplicate points only appear as the one with the lowest index.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Triangulation<N: Float> {
+    points: Vec<Point2d<N>>,
+    triangles: Vec<[usize; 3]>,
+    neighbours: Vec<[Option<usize>; 3]>,
+    constrained: Vec<[bool; 3]>,
+}
+
+/// Why constrained edges couldn't be added.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TriangulationError {
+    /// A constrained edge has an end which isn't one of the points.
+    InvalidIndex(usize),
+    /// A constrained edge crosses another one, so they can't both be edges.  These are the ends of the second.
+    CrossingConstraints(usize, usize),
+}
+
+impl Display for TriangulationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            TriangulationError::InvalidIndex(index) => write!(f, "no point with index {}", index),
+            TriangulationError::CrossingConstraints(a, b) => write!(f, "the constrained edge from {} to {} crosses another", a, b),
+        }
+    }
+}
+
+impl core::error::Error for TriangulationError {}
+
+// Accessors for triangulations //
+
+impl<N: Float> Triangulation<N> {
+    /// The points that were triangulated, including any duplicates.
+    pub fn points(&self) -> &[Point2d<N>] {
+        &self.points
+    }
+
+    /// The triangles, as counterclockwise indices into `points()`.
+    pub fn triangles(&self) -> &[[usize; 3]] {
+        &self.triangles
+    }
+
+    /// The triangle across each edge of each triangle.  `None` across the hull, or the boundary of a polygon.
+    pub fn neighbours(&self) -> &[[Option<usize>; 3]] {
+        &self.neighbours
+    }
+
+    /// Whether an edge of a triangle is constrained.  See `Triangulation` for how edges are numbered.
+    pub fn is_constrained(&self, triangle: usize, edge: usize) -> bool {
+        self.constrained[triangle][edge]
+    }
+
+    /// The corners of a triangle.
+    pub fn triangle(&self, index: usize) -> [Point2d<N>; 3] {
+        self.triangles[index].map(|i| self.points[i])
+    }
+
+    /// How many triangles there are.
+    pub fn len(&self) -> usize {
+        self.triangles.len()
+    }
+
+    /// Whether there are no triangles, such as when every point is on one line.
+    pub fn is_empty(&self) -> bool {
+        self.triangles.is_empty()
+    }
+}
+
+// Triangulation //
+
+/// The Delaunay triangulation of some points: no point is inside the circle through any triangle's corners.
+/// When four or more points are on one circle, which of the possible triangulations comes out depends on the order
+/// of the points.
+pub fn delaunay<N: Float>(points: &[Point2d<N>]) -> Triangulation<N> {
+    let mut builder = Builder::new(points);
+    builder.sweep();
+    builder.legalize_all();
+    builder.finish()
+}
+
+/// The constrained Delaunay triangulation of some points: every edge in `edges` is in it, and otherwise it's as
+/// close to Delaunay as it can be.  An edge which runs through other points is split at them.
+pub fn constrained_delaunay<N: Float>(points: &[Point2d<N>], edges: &[[usize; 2]]) -> Result<Triangulation<N>, TriangulationError> {
+    let mut builder = Builder::new(points);
+    builder.sweep();
+    builder.legalize_all();
+    for &[a, b] in edges {
+        builder.insert_constraint(a, b)?;
+    }
+    builder.legalize_all();
+    Ok(builder.finish())
+}
+
+/// A constrained Delaunay triangulation of the inside of a polygon with holes, for meshing and navigation.
+/// The points are the boundary's vertices, then each hole's, in order.
+pub fn triangulate_polygon<N: Float>(polygon: &PolygonWithHoles<N>) -> Result<Triangulation<N>, TriangulationError> {
+    let mut points = Vec::new();
+    let mut edges = Vec::new();
+    for ring in core::iter::once(polygon.exterior()).chain(polygon.holes().iter()) {
+        let first = points.len();
+        points.extend_from_slice(ring.vertices());
+        for i in 0..ring.len() {
+            edges.push([first + i, first + (i + 1) % ring.len()]);
+        }
+    }
+    let mut builder = Builder::new(&points);
+    builder.sweep();
+    builder.legalize_all();
+    for &[a, b] in edges.iter() {
+        builder.insert_constraint(a, b)?;
+    }
+    builder.legalize_all();
+    builder.remove_outside();
+    Ok(builder.finish())
+}
+
+/// A triangulation in progress.
+struct Builder<'a, N: Float> {
+    points: &'a [Point2d<N>],
+    /// The lowest index of a point equal to each point.
+    canonical: Vec<usize>,
+    /// The points without duplicates, left to right, then bottom to top.
+    order: Vec<usize>,
+    triangles: Vec<[usize; 3]>,
+    neighbours: Vec<[Option<usize>; 3]>,
+    constrained: Vec<[bool; 3]>,
+    /// A triangle touching each point, to start walking around it from.
+    vertex_triangle: Vec<Option<usize>>,
+}
+
+impl<'a, N: Float> Builder<'a, N> {
+    fn new(points: &'a [Point2d<N>]) -> Builder<'a, N> {
+        let (order, canonical) = sort_points(points);
+        Builder {
+            points,
+            canonical,
+            order,
+            triangles: Vec::new(),
+            neighbours: Vec::new(),
+            constrained: Vec::new(),
+            vertex_triangle: alloc::vec![None; points.len()],
+        }
+    }
+
+    fn finish(self) -> Triangulation<N> {
+        Triangulation {
+            points: self.points.to_vec(),
+            triangles: self.triangles,
+            neighbours: self.neighbours,
+            constrained: self.constrained,
+        }
+    }
+
+    fn orient(&self, a: usize, b: usize, c: usize) -> Orientation {
+        orient2d(&self.points[a], &self.points[b], &self.points[c])
+    }
+
+    fn add_triangle(&mut self, corners: [usize; 3]) -> usize {
+        let index = self.triangles.len();
+        self.triangles.push(corners);
+        self.neighbours.push([None; 3]);
+        self.constrained.push([false; 3]);
+        for corner in corners {
+            self.vertex_triangle[corner] = Some(index);
+        }
+        index
+    }
+
+    /// Point the edge of `triangle` between `a` and `b` at `neighbour`.
+    fn link(&mut self, triangle: usize, a: usize, b: usize, neighbour: Option<usize>) {
+        let edge = self.opposite(triangle, a, b);
+        self.neighbours[triangle][edge] = neighbour;
+    }
+
+    /// Which corner of a triangle is neither `a` nor `b`, which is the number of the edge between them.
+    fn opposite(&self, triangle: usize, a: usize, b: usize) -> usize {
+        let corners = self.triangles[triangle];
+        (0..3).find(|&i| corners[i] != a && corners[i] != b).unwrap_or(0)
+    }
+
+    // Sweeping //
+
+    /// Triangulate the points, left to right, keeping the hull as a counterclockwise linked loop.
+    fn sweep(&mut self) {
+        let order = self.order.clone();
+        if order.len() < 3 {
+            return;
+        }
+        // Points on one line don't make any triangles, until one that isn't comes along.
+        let apex = match (2..order.len()).find(|&k| self.orient(order[0], order[1], order[k]) != Orientation::Collinear) {
+            Some(apex) => apex,
+            None => return,
+        };
+        let p = order[apex];
+
+        let n = self.points.len();
+        let mut next = alloc::vec![usize::MAX; n];
+        let mut previous = alloc::vec![usize::MAX; n];
+        // The triangle on the inside of the hull edge from each vertex to the next.
+        let mut hull_triangle = alloc::vec![usize::MAX; n];
+
+        // A fan from the apex to the line of points before it.
+        let above = self.orient(order[0], order[1], p) == Orientation::CounterClockwise;
+        let mut last: Option<usize> = None;
+        for pair in order[..apex].windows(2) {
+            let (u, v) = if above { (pair[0], pair[1]) } else { (pair[1], pair[0]) };
+            let triangle = self.add_triangle([u, v, p]);
+            if let Some(last) = last {
+                let shared = if above { u } else { v };
+                self.link(triangle, shared, p, Some(last));
+                self.link(last, shared, p, Some(triangle));
+            }
+            last = Some(triangle);
+            next[u] = v;
+            previous[v] = u;
+            hull_triangle[u] = triangle;
+        }
+        // The hull goes up the line and back through the apex, or the other way around if the apex is below it.
+        let (first, last) = (order[0], order[apex - 1]);
+        // The fan triangle touching the first point on the line, and the one touching the last.
+        let (at_first, at_last) = (0, apex - 2);
+        let (into_apex, out_of_apex) = if above { (last, first) } else { (first, last) };
+        next[into_apex] = p;
+        previous[p] = into_apex;
+        next[p] = out_of_apex;
+        previous[out_of_apex] = p;
+        hull_triangle[into_apex] = if above { at_last } else { at_first };
+        hull_triangle[p] = if above { at_first } else { at_last };
+
+        let mut newest = p;
+        for &p in order[apex + 1..].iter() {
+            // The newest point is the furthest along so far, so it's on the hull, and next to the edges `p` can see.
+            let visible = |builder: &Self, u: usize| builder.orient(u, next[u], p) == Orientation::Clockwise;
+            let mut start = newest;
+            while visible(self, previous[start]) && previous[start] != newest {
+                start = previous[start];
+            }
+            let mut end = newest;
+            while visible(self, end) && next[end] != start {
+                end = next[end];
+            }
+            if start == end {
+                // Shouldn't happen, but if rounding gets in the way, look around the whole hull for an edge.
+                match core::iter::successors(Some(newest), |&u| Some(next[u]).filter(|&v| v != newest)).find(|&u| visible(self, u)) {
+                    Some(u) => {
+                        start = u;
+                        end = u;
+                        while visible(self, end) {
+                            end = next[end];
+                        }
+                    },
+                    None => continue,
+                }
+            }
+
+            let mut u = start;
+            let mut last: Option<usize> = None;
+            while u != end {
+                let v = next[u];
+                let triangle = self.add_triangle([u, p, v]);
+                let outside = hull_triangle[u];
+                self.link(triangle, u, v, Some(outside));
+                self.link(outside, u, v, Some(triangle));
+                if let Some(last) = last {
+                    self.link(triangle, u, p, Some(last));
+                    self.link(last, u, p, Some(triangle));
+                } else {
+                    hull_triangle[u] = triangle;
+                }
+                last = Some(triangle);
+                u = v;
+            }
+            next[start] = p;
+            previous[p] = start;
+            next[p] = end;
+            previous[end] = p;
+            if let Some(last) = last {
+                hull_triangle[p] = last;
+            }
+            newest = p;
+        }
+    }
+
+    // Flipping //
+
+    /// Flip the edge opposite corner `edge` of `triangle`, so it joins the other two corners instead.
+    ///
+    /// With the triangle as (v0, v1, v2) and the one across as (w, v2, v1), they become (v0, v1, w) and (w, v2, v0).
+    /// The triangles keep their indices.  Returns the index of the other triangle.
+    fn flip(&mut self, triangle: usize, edge: usize) -> usize {
+        let other = self.neighbours[triangle][edge].unwrap_or(triangle);
+        let corners = self.triangles[triangle];
+        let (v0, v1, v2) = (corners[edge], corners[(edge + 1) % 3], corners[(edge + 2) % 3]);
+        let far = self.opposite(other, v1, v2);
+        let w = self.triangles[other][far];
+
+        // What's across each outer edge, and whether it's constrained, before anything moves.
+        let across = |builder: &Self, t: usize, a: usize, b: usize| {
+            let i = builder.opposite(t, a, b);
+            (builder.neighbours[t][i], builder.constrained[t][i])
+        };
+        let (v0_v1, v0_v1_constrained) = across(self, triangle, v0, v1);
+        let (v2_v0, v2_v0_constrained) = across(self, triangle, v2, v0);
+        let (v1_w, v1_w_constrained) = across(self, other, v1, w);
+        let (w_v2, w_v2_constrained) = across(self, other, w, v2);
+
+        self.triangles[triangle] = [v0, v1, w];
+        self.neighbours[triangle] = [v1_w, Some(other), v0_v1];
+        self.constrained[triangle] = [v1_w_constrained, false, v0_v1_constrained];
+        self.triangles[other] = [w, v2, v0];
+        self.neighbours[other] = [v2_v0, Some(triangle), w_v2];
+        self.constrained[other] = [v2_v0_constrained, false, w_v2_constrained];
+
+        // Two of the outer triangles changed sides.
+        if let Some(outside) = v1_w {
+            self.link(outside, v1, w, Some(triangle));
+        }
+        if let Some(outside) = v2_v0 {
+            self.link(outside, v2, v0, Some(other));
+        }
+        for (corner, t) in [(v0, triangle), (v1, triangle), (w, triangle), (v2, other)] {
+            self.vertex_triangle[corner] = Some(t);
+        }
+        other
+    }
+
+    /// Whether an edge fails the incircle test, so flipping it would make it Delaunay.
+    fn is_illegal(&self, triangle: usize, edge: usize) -> bool {
+        let other = match self.neighbours[triangle][edge] {
+            Some(other) if !self.constrained[triangle][edge] => other,
+            _ => return false,
+        };
+        let corners = self.triangles[triangle];
+        let w = self.triangles[other][self.opposite(other, corners[(edge + 1) % 3], corners[(edge + 2) % 3])];
+        let [a, b, c] = corners.map(|i| &self.points[i]);
+        incircle(a, b, c, &self.points[w]) == Ordering::Greater
+    }
+
+    /// Flip edges until every unconstrained edge passes the incircle test.
+    fn legalize_all(&mut self) {
+        let mut stack: Vec<(usize, usize)> = (0..self.triangles.len())
+            .flat_map(|t| (0..3).map(move |i| (t, i)))
+            .collect();
+        while let Some((triangle, edge)) = stack.pop() {
+            if self.is_illegal(triangle, edge) {
+                let other = self.flip(triangle, edge);
+                // The outer edges of the two new triangles might need flipping now.
+                stack.extend([(triangle, 0), (triangle, 2), (other, 0), (other, 2)]);
+            }
+        }
+    }
+
+    // Constraints //
+
+    /// The triangles around a point.
+    fn around(&self, vertex: usize) -> Vec<usize> {
+        let mut found = Vec::new();
+        let start = match self.vertex_triangle[vertex] {
+            Some(start) => start,
+            None => return found,
+        };
+        // Counterclockwise, across the edge from the point to its third corner...
+        let mut triangle = start;
+        loop {
+            found.push(triangle);
+            let k = self.corner(triangle, vertex);
+            match self.neighbours[triangle][(k + 1) % 3] {
+                Some(next) if next == start => return found,
+                Some(next) => triangle = next,
+                None => break,
+            }
+        }
+        // ...and if that reaches the hull, clockwise from the start.
+        triangle = start;
+        while let Some(next) = self.neighbours[triangle][(self.corner(triangle, vertex) + 2) % 3] {
+            found.push(next);
+            triangle = next;
+        }
+        found
+    }
+
+    fn corner(&self, triangle: usize, vertex: usize) -> usize {
+        self.triangles[triangle].iter().position(|&corner| corner == vertex).unwrap_or(0)
+    }
+
+    /// The triangle with an edge between two points, and the edge's number in it.
+    fn find_edge(&self, a: usize, b: usize) -> Option<(usize, usize)> {
+        self.around(a).into_iter().find_map(|t| {
+            let k = self.corner(t, a);
+            let corners = self.triangles[t];
+            if corners[(k + 1) % 3] == b {
+                Some((t, (k + 2) % 3))
+            } else if corners[(k + 2) % 3] == b {
+                Some((t, (k + 1) % 3))
+            } else {
+                None
+            }
+        })
+    }
+
+    fn mark_constrained(&mut self, triangle: usize, edge: usize) {
+        self.constrained[triangle][edge] = true;
+        if let Some(other) = self.neighbours[triangle][edge] {
+            let corners = self.triangles[triangle];
+            let i = self.opposite(other, corners[(edge + 1) % 3], corners[(edge + 2) % 3]);
+            self.constrained[other][i] = true;
+        }
+    }
+
+    fn insert_constraint(&mut self, a: usize, b: usize) -> Result<(), TriangulationError> {
+        for end in [a, b] {
+            if end >= self.points.len() {
+                return Err(TriangulationError::InvalidIndex(end));
+            }
+        }
+        let mut pending = alloc::vec![(self.canonical[a], self.canonical[b])];
+        while let Some((from, to)) = pending.pop() {
+            if from == to || self.vertex_triangle[from].is_none() {
+                continue;
+            }
+            if let Some((triangle, edge)) = self.find_edge(from, to) {
+                self.mark_constrained(triangle, edge);
+                continue;
+            }
+            let (crossed, stop) = self.crossed_edges(from, to).ok_or(TriangulationError::CrossingConstraints(a, b))?;
+            if stop != to {
+                // The edge runs through another point, so it's two edges.
+                pending.push((stop, to));
+            }
+            self.remove_crossings(from, stop, crossed);
+            if let Some((triangle, edge)) = self.find_edge(from, stop) {
+                self.mark_constrained(triangle, edge);
+            }
+        }
+        Ok(())
+    }
+
+    /// The edges crossed by the segment from one point to another, in order, walking from the first point.  If the
+    /// segment runs into another point first, the walk stops there, and that point is returned too.
+    /// `None` if a crossed edge is constrained.
+    fn crossed_edges(&self, from: usize, to: usize) -> Option<(Vec<(usize, usize)>, usize)> {
+        let mut crossed = Vec::new();
+        // The triangle around `from` which the segment leaves through, and the ends of that edge, right then left.
+        let mut exit = None;
+        for t in self.around(from) {
+            let k = self.corner(t, from);
+            let corners = self.triangles[t];
+            let (right, left) = (corners[(k + 1) % 3], corners[(k + 2) % 3]);
+            for end in [right, left] {
+                if self.orient(from, to, end) == Orientation::Collinear && self.is_ahead(from, to, end) {
+                    // Already an edge, and it's along the way.
+                    return Some((crossed, end));
+                }
+            }
+            if self.orient(from, to, right) == Orientation::Clockwise && self.orient(from, to, left) == Orientation::CounterClockwise {
+                exit = Some((t, right, left));
+                break;
+            }
+        }
+        let (mut triangle, mut right, mut left) = exit?;
+        loop {
+            let edge = self.opposite(triangle, right, left);
+            if self.constrained[triangle][edge] {
+                return None;
+            }
+            crossed.push((right, left));
+            let next = self.neighbours[triangle][edge]?;
+            let w = self.triangles[next][self.opposite(next, right, left)];
+            if w == to {
+                return Some((crossed, to));
+            }
+            match self.orient(from, to, w) {
+                Orientation::Collinear => return Some((crossed, w)),
+                Orientation::Clockwise => right = w,
+                Orientation::CounterClockwise => left = w,
+            }
+            triangle = next;
+        }
+    }
+
+    /// Whether a point on the line from `from` to `to` is on the same side of `from` as `to`.
+    fn is_ahead(&self, from: usize, to: usize, point: usize) -> bool {
+        let (a, b, p) = (&self.points[from], &self.points[to], &self.points[point]);
+        (b.x - a.x) * (p.x - a.x) + (b.y - a.y) * (p.y - a.y) > N::zero()
+    }
+
+    /// Flip away every edge crossing the segment between two points, so it becomes an edge.
+    /// An edge can only be flipped when the two triangles around it make a convex quadrilateral, so edges which
+    /// can't be flipped yet go to the back of the queue.
+    fn remove_crossings(&mut self, from: usize, to: usize, crossed: Vec<(usize, usize)>) {
+        let mut queue: VecDeque<(usize, usize)> = crossed.into();
+        while let Some((a, b)) = queue.pop_front() {
+            let (triangle, edge) = match self.find_edge(a, b) {
+                Some(found) => found,
+                None => continue,
+            };
+            let other = match self.neighbours[triangle][edge] {
+                Some(other) => other,
+                None => continue,
+            };
+            let corners = self.triangles[triangle];
+            let (v0, v1, v2) = (corners[edge], corners[(edge + 1) % 3], corners[(edge + 2) % 3]);
+            let w = self.triangles[other][self.opposite(other, v1, v2)];
+            let convex = self.orient(v0, v1, w) == Orientation::CounterClockwise
+                && self.orient(w, v2, v0) == Orientation::CounterClockwise;
+            if !convex {
+                queue.push_back((a, b));
+                continue;
+            }
+            self.flip(triangle, edge);
+            if self.crosses(from, to, v0, w) {
+                queue.push_back((v0, w));
+            }
+        }
+    }
+
+    /// Whether the segments between two pairs of points cross, other than at their ends.
+    fn crosses(&self, a: usize, b: usize, c: usize, d: usize) -> bool {
+        if a == c || a == d || b == c || b == d {
+            return false;
+        }
+        let opposite = |x: Orientation, y: Orientation| x != Orientation::Collinear && y != Orientation::Collinear && x != y;
+        opposite(self.orient(a, b, c), self.orient(a, b, d)) && opposite(self.orient(c, d, a), self.orient(c, d, b))
+    }
+
+    // Polygons //
+
+    /// Drop the triangles outside a polygon made of the constrained edges.  Starting from the hull, a triangle is
+    /// inside when getting to it crosses an odd number of constrained edges.
+    fn remove_outside(&mut self) {
+        let count = self.triangles.len();
+        let mut depth = alloc::vec![usize::MAX; count];
+        let mut queue: VecDeque<(usize, usize)> = VecDeque::new();
+        for (t, neighbours) in self.neighbours.iter().enumerate() {
+            for i in (0..3).filter(|&i| neighbours[i].is_none()) {
+                let start = if self.constrained[t][i] { 1 } else { 0 };
+                if start < depth[t] {
+                    depth[t] = start;
+                    queue.push_back((t, start));
+                }
+            }
+        }
+        // Breadth first, with crossing a constrained edge costing one, so each triangle gets the fewest crossings.
+        while let Some((t, d)) = queue.pop_front() {
+            if d > depth[t] {
+                continue;
+            }
+            for i in 0..3 {
+                if let Some(next) = self.neighbours[t][i] {
+                    let cost = if self.constrained[t][i] { 1 } else { 0 };
+                    if d + cost < depth[next] {
+                        depth[next] = d + cost;
+                        if cost == 0 { queue.push_front((next, d)) } else { queue.push_back((next, d + 1)) }
+                    }
+                }
+            }
+        }
+
+        let mut index = alloc::vec![None; count];
+        let mut kept = 0;
+        for (t, d) in depth.iter().enumerate() {
+            if d % 2 == 1 {
+                index[t] = Some(kept);
+                kept += 1;
+            }
+        }
+        let mut triangles = Vec::with_capacity(kept);
+        let mut neighbours = Vec::with_capacity(kept);
+        let mut constrained = Vec::with_capacity(kept);
+        for t in (0..count).filter(|&t| index[t].is_some()) {
+            triangles.push(self.triangles[t]);
+            neighbours.push(self.neighbours[t].map(|neighbour| neighbour.and_then(|n| index[n])));
+            constrained.push(self.constrained[t]);
+        }
+        self.triangles = triangles;
+        self.neighbours = neighbours;
+        self.constrained = constrained;
+    }
+}
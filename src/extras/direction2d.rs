@@ -0,0 +1,193 @@
+use core::{fmt::Display, ops::{Add, Div, Mul, Neg, Sub}};
+
+use num_traits::Float;
+
+use crate::defs::bivector::Bivector;
+
+use super::{point2d::Point2d, transformations::RigidTransformation};
+
+/// In 2d pga, a direction is a point at infinity.  That is, a bivector with e12 = 0.  This can be converted to and
+/// from the bivector struct, as a convenient handle, in the same way `Point2d` is for finite points.
+///
+/// Unlike points, directions form a vector space: they can be added, subtracted, and scaled.  The difference of two
+/// points is a direction, and a point plus a direction is a point.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Direction2d<N: Float> {
+    pub x: N,
+    pub y: N
+}
+
+// Constructors and conversions for direction 2d //
+
+impl<N: Float> Direction2d<N> {
+    /// Create a new direction.
+    /// This is a `const fn`, so it can be used to build `const` and `static` directions.
+    pub const fn new(x: N, y: N) -> Direction2d<N> {
+        Direction2d {x, y}
+    }
+
+    /// Cast your direction to a bivector.  This is a point at infinity, so e12 = 0.
+    pub fn to_bivector(&self) -> Bivector<N> {
+        Bivector {
+            e12: N::zero(),
+            e20: self.x,
+            e01: self.y,
+        }
+    }
+
+    /// Build a direction from a bivector.  The e12 part is ignored, so for a finite point this gives its position
+    /// scaled by its weight.
+    pub fn from_bivector(bivector: &Bivector<N>) -> Direction2d<N> {
+        Direction2d {
+            x: bivector.e20,
+            y: bivector.e01,
+        }
+    }
+}
+
+// Vector operations //
+
+impl<N: Float> Direction2d<N> {
+    /// The length of this direction.
+    pub fn length(&self) -> N {
+        self.x.hypot(self.y)
+    }
+
+    /// This direction, scaled to length 1.
+    pub fn normalized(&self) -> Direction2d<N> {
+        *self / self.length()
+    }
+
+    /// The dot product of two directions.
+    pub fn dot(&self, other: &Direction2d<N>) -> N {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// This direction, rotated a quarter turn counterclockwise.
+    pub fn perpendicular(&self) -> Direction2d<N> {
+        Direction2d {
+            x: -self.y,
+            y: self.x,
+        }
+    }
+}
+
+// Traits
+
+impl<N: Float+Display> Display for Direction2d<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "<{}, {}>", self.x, self.y)
+    }
+}
+
+impl<N: Float> From<Direction2d<N>> for Bivector<N> {
+    fn from(direction: Direction2d<N>) -> Self {
+        direction.to_bivector()
+    }
+}
+
+impl<N: Float> Add<Direction2d<N>> for Direction2d<N> {
+    type Output = Self;
+
+    fn add(self, rhs: Direction2d<N>) -> Self::Output {
+        Direction2d {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y
+        }
+    }
+}
+
+impl<N: Float> Sub<Direction2d<N>> for Direction2d<N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Direction2d<N>) -> Self::Output {
+        Direction2d {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y
+        }
+    }
+}
+
+impl<N: Float> Neg for Direction2d<N> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Direction2d {
+            x: -self.x,
+            y: -self.y
+        }
+    }
+}
+
+impl<N: Float> Mul<N> for Direction2d<N> {
+    type Output = Self;
+
+    fn mul(self, rhs: N) -> Self::Output {
+        Direction2d {
+            x: self.x * rhs,
+            y: self.y * rhs
+        }
+    }
+}
+
+impl<N: Float> Div<N> for Direction2d<N> {
+    type Output = Self;
+
+    fn div(self, rhs: N) -> Self::Output {
+        Direction2d {
+            x: self.x / rhs,
+            y: self.y / rhs
+        }
+    }
+}
+
+/// The difference of two points is the direction from the second to the first.
+impl<N: Float> Sub<Point2d<N>> for Point2d<N> {
+    type Output = Direction2d<N>;
+
+    /// Subtract two points.
+    /// ```rust
+    /// use simply_2dpga::extras::{point2d::*, direction2d::*};
+    ///
+    /// let p1 = Point2d::new(4.0, 6.0);
+    /// let p2 = Point2d::new(1.0, 2.0);
+    /// assert_eq!(p1 - p2, Direction2d::new(3.0, 4.0));
+    /// assert_eq!(p2 + (p1 - p2), p1);
+    /// ```
+    fn sub(self, rhs: Point2d<N>) -> Self::Output {
+        Direction2d {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y
+        }
+    }
+}
+
+/// Moving a point along a direction gives another point.
+impl<N: Float> Add<Direction2d<N>> for Point2d<N> {
+    type Output = Self;
+
+    fn add(self, rhs: Direction2d<N>) -> Self::Output {
+        Point2d {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y
+        }
+    }
+}
+
+impl<N: Float> Sub<Direction2d<N>> for Point2d<N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Direction2d<N>) -> Self::Output {
+        Point2d {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y
+        }
+    }
+}
+
+/// Anything which can transform a bivector can transform a direction.  Translations leave directions alone.
+impl<N: Float, T: RigidTransformation<Bivector<N>>> RigidTransformation<Direction2d<N>> for T {
+    fn apply(&self, target: &Direction2d<N>) -> Direction2d<N> {
+        Direction2d::from_bivector(&self.apply(&target.to_bivector()))
+    }
+}
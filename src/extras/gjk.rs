@@ -0,0 +1,460 @@
+use num_traits::Float;
+
+use crate::defs::{vector::Vector, bivector::Bivector};
+
+#[cfg(feature = "alloc")]
+use super::polygon2d::Polygon2d;
+use super::{
+    capsule2d::Capsule2d,
+    circle2d::Circle2d,
+    direction2d::Direction2d,
+    line2d::default_tolerance,
+    point2d::Point2d,
+    segment2d::Segment2d,
+    transformations::RigidTransformation,
+};
+
+// GJK AND EPA //
+// Two convex shapes overlap exactly when their Minkowski difference, every `b - a` for `a` in one and `b` in the
+// other, contains the origin, and the distance between them is the distance from the origin to the difference.
+// The difference is never built.  GJK walks a simplex of up to three of its points towards the origin, finding each
+// new point from the shapes' support points, until the simplex either contains the origin or can't get any closer.
+// When the shapes overlap, EPA grows that triangle into a polygon inside the difference, pushing out whichever edge
+// is closest to the origin until it's on the boundary.  That edge is the shortest way out.
+// Round shapes are a core (a point, or a segment) and a radius.  GJK only sees the cores, which have corners, so it
+// finishes exactly, and the radii are taken off at the end.
+// The simplex and polytope are fixed size arrays, so this doesn't need the `alloc` feature.
+
+/// The largest number of steps GJK takes, which it only needs when it's crawling round a rounded corner.
+const MAX_GJK_ITERATIONS: usize = 32;
+
+/// The most vertices EPA's polygon can have.
+const MAX_EPA_VERTICES: usize = 64;
+
+/// A convex shape, described by its support points: for any direction, the point of the shape furthest along it.
+///
+/// The shape is a core, whose support points these are, grown by `radius` all the way round.  Polygons have no
+/// radius, and circles and capsules are a point and a segment with one.
+pub trait SupportMapping<N: Float> {
+    /// The point of the core furthest along `direction`, which doesn't have to be normalized.  If there's a tie, any
+    /// of them will do.
+    fn support(&self, direction: &Direction2d<N>) -> Point2d<N>;
+
+    /// How far the shape reaches past its core.
+    fn radius(&self) -> N {
+        N::zero()
+    }
+}
+
+/// A shape moved by a rigid transformation, such as a `Motor`, `Rotor` or `MultiTransform`, without building a moved
+/// copy of it.
+///
+/// The transformation is applied to the origin and the two axes up front.  Directions are turned back into the
+/// shape's frame with those, and support points are brought out with them.
+#[derive(Clone, Copy, Debug)]
+pub struct Transformed<'a, N: Float, S: SupportMapping<N>> {
+    shape: &'a S,
+    origin: Point2d<N>,
+    x_axis: Direction2d<N>,
+    y_axis: Direction2d<N>,
+}
+
+impl<'a, N: Float, S: SupportMapping<N>> Transformed<'a, N, S> {
+    /// Move `shape` by `transformation`.
+    pub fn new<T: RigidTransformation<Bivector<N>>>(shape: &'a S, transformation: &T) -> Transformed<'a, N, S> {
+        let (zero, one) = (N::zero(), N::one());
+        Transformed {
+            shape,
+            origin: Point2d::from_bivector(&transformation.apply(&Point2d::new(zero, zero).to_bivector())),
+            x_axis: Direction2d::from_bivector(&transformation.apply(&Direction2d::new(one, zero).to_bivector())),
+            y_axis: Direction2d::from_bivector(&transformation.apply(&Direction2d::new(zero, one).to_bivector())),
+        }
+    }
+
+    /// The shape, before it's moved.
+    pub fn shape(&self) -> &'a S {
+        self.shape
+    }
+}
+
+/// How far apart two shapes are.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Separation<N: Float> {
+    pub distance: N,
+    /// The point of the first shape closest to the second, normalized.
+    pub a: Bivector<N>,
+    /// The point of the second shape closest to the first, normalized.
+    pub b: Bivector<N>,
+}
+
+impl<N: Float> Separation<N> {
+    /// The line from `a` to `b`, which is perpendicular to both shapes.  Its norm is the distance.
+    pub fn line(&self) -> Vector<N> {
+        Point2d::line_between_points(Point2d::from_bivector(&self.a), Point2d::from_bivector(&self.b))
+    }
+}
+
+/// How far two shapes overlap.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Penetration<N: Float> {
+    pub depth: N,
+    /// The way to move the second shape, by `depth`, to separate them.  A point at infinity, normalized to length 1.
+    pub normal: Bivector<N>,
+    /// The point of the first shape deepest inside the second, normalized.
+    pub a: Bivector<N>,
+    /// The point of the second shape deepest inside the first, normalized.  It's `depth` from `a`, against the
+    /// normal.
+    pub b: Bivector<N>,
+}
+
+/// Whether two shapes are apart or overlapping, and by how much.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Proximity<N: Float> {
+    Separated(Separation<N>),
+    /// They overlap, or touch, in which case the depth is about zero.
+    Penetrating(Penetration<N>),
+}
+
+// Queries //
+
+/// Whether two convex shapes overlap, or touch.  This only runs GJK, so it's cheaper than `proximity`.
+pub fn intersects<N: Float, A: SupportMapping<N>, B: SupportMapping<N>>(a: &A, b: &B) -> bool {
+    match closest_cores(a, b, default_tolerance()) {
+        Cores::Apart(simplex) => simplex.closest().length() <= a.radius() + b.radius(),
+        Cores::Overlapping(_) => true,
+    }
+}
+
+/// How far apart two convex shapes are, or `None` if they overlap.
+pub fn distance<N: Float, A: SupportMapping<N>, B: SupportMapping<N>>(a: &A, b: &B) -> Option<Separation<N>> {
+    match proximity(a, b) {
+        Proximity::Separated(separation) => Some(separation),
+        Proximity::Penetrating(_) => None,
+    }
+}
+
+/// How far two convex shapes overlap, or `None` if they're apart.
+pub fn penetration<N: Float, A: SupportMapping<N>, B: SupportMapping<N>>(a: &A, b: &B) -> Option<Penetration<N>> {
+    match proximity(a, b) {
+        Proximity::Separated(_) => None,
+        Proximity::Penetrating(penetration) => Some(penetration),
+    }
+}
+
+/// The distance between two convex shapes, or how far they overlap, with the default tolerance.  See
+/// `proximity_with_tolerance`.
+pub fn proximity<N: Float, A: SupportMapping<N>, B: SupportMapping<N>>(a: &A, b: &B) -> Proximity<N> {
+    proximity_with_tolerance(a, b, default_tolerance())
+}
+
+/// The distance between two convex shapes, or how far they overlap.
+///
+/// `tolerance` is relative to the size of the shapes.  GJK and EPA stop when a step gains less than that, and cores
+/// closer than that are treated as touching.
+pub fn proximity_with_tolerance<N: Float, A: SupportMapping<N>, B: SupportMapping<N>>(a: &A, b: &B, tolerance: N) -> Proximity<N> {
+    let radius = a.radius() + b.radius();
+    let (core_a, core_b, normal, depth) = match closest_cores(a, b, tolerance) {
+        Cores::Apart(simplex) => {
+            let (core_a, core_b) = simplex.closest_points();
+            let offset = core_b - core_a;
+            let distance = offset.length();
+            let normal = offset / distance;
+            if distance > radius {
+                return Proximity::Separated(Separation {
+                    distance: distance - radius,
+                    a: (core_a + normal * a.radius()).to_bivector(),
+                    b: (core_b - normal * b.radius()).to_bivector(),
+                });
+            }
+            (core_a, core_b, normal, radius - distance)
+        },
+        Cores::Overlapping(simplex) => {
+            let (core_a, core_b, normal, depth) = expand(a, b, &simplex, tolerance);
+            (core_a, core_b, normal, depth + radius)
+        },
+    };
+    Proximity::Penetrating(Penetration {
+        depth,
+        normal: normal.to_bivector(),
+        a: (core_a + normal * a.radius()).to_bivector(),
+        b: (core_b - normal * b.radius()).to_bivector(),
+    })
+}
+
+// Support mappings //
+
+impl<N: Float> SupportMapping<N> for Point2d<N> {
+    fn support(&self, _direction: &Direction2d<N>) -> Point2d<N> {
+        *self
+    }
+}
+
+impl<N: Float> SupportMapping<N> for Segment2d<N> {
+    fn support(&self, direction: &Direction2d<N>) -> Point2d<N> {
+        if self.direction().dot(direction) > N::zero() { self.end } else { self.start }
+    }
+}
+
+/// The polygon has to be convex, and can go either way around.  An empty polygon is just the origin.
+/// Requires the `alloc` feature.
+#[cfg(feature = "alloc")]
+impl<N: Float> SupportMapping<N> for Polygon2d<N> {
+    fn support(&self, direction: &Direction2d<N>) -> Point2d<N> {
+        let along = |point: &Point2d<N>| point.x * direction.x + point.y * direction.y;
+        let mut vertices = self.vertices().iter();
+        let Some(mut best) = vertices.next() else {
+            return Point2d::new(N::zero(), N::zero());
+        };
+        for vertex in vertices {
+            if along(vertex) > along(best) {
+                best = vertex;
+            }
+        }
+        *best
+    }
+}
+
+/// A circle is its centre, with its radius.
+impl<N: Float> SupportMapping<N> for Circle2d<N> {
+    fn support(&self, _direction: &Direction2d<N>) -> Point2d<N> {
+        self.center
+    }
+
+    fn radius(&self) -> N {
+        self.radius
+    }
+}
+
+/// A capsule is its segment, with its radius.
+impl<N: Float> SupportMapping<N> for Capsule2d<N> {
+    fn support(&self, direction: &Direction2d<N>) -> Point2d<N> {
+        self.segment.support(direction)
+    }
+
+    fn radius(&self) -> N {
+        self.radius
+    }
+}
+
+impl<N: Float, S: SupportMapping<N>> SupportMapping<N> for Transformed<'_, N, S> {
+    fn support(&self, direction: &Direction2d<N>) -> Point2d<N> {
+        let local = Direction2d::new(direction.dot(&self.x_axis), direction.dot(&self.y_axis));
+        let point = self.shape.support(&local);
+        self.origin + self.x_axis * point.x + self.y_axis * point.y
+    }
+
+    fn radius(&self) -> N {
+        self.shape.radius()
+    }
+}
+
+// Helpers //
+
+/// A point of the difference of the cores, with the points of each core it came from.
+#[derive(Clone, Copy, Debug)]
+struct Vertex<N: Float> {
+    a: Point2d<N>,
+    b: Point2d<N>,
+    /// `b - a`.
+    w: Direction2d<N>,
+}
+
+impl<N: Float> Vertex<N> {
+    /// The support point of the difference along `direction`: the furthest point of `b` that way, less the furthest
+    /// point of `a` the other way.
+    fn support<A: SupportMapping<N>, B: SupportMapping<N>>(a: &A, b: &B, direction: Direction2d<N>) -> Vertex<N> {
+        let a = a.support(&-direction);
+        let b = b.support(&direction);
+        Vertex { a, b, w: b - a }
+    }
+}
+
+/// GJK's simplex, with the weights which give its closest point to the origin.
+#[derive(Clone, Copy, Debug)]
+struct Simplex<N: Float> {
+    vertices: [Vertex<N>; 3],
+    weights: [N; 3],
+    len: usize,
+}
+
+impl<N: Float> Simplex<N> {
+    fn new(vertex: Vertex<N>) -> Simplex<N> {
+        Simplex { vertices: [vertex; 3], weights: [N::one(), N::zero(), N::zero()], len: 1 }
+    }
+
+    fn vertices(&self) -> &[Vertex<N>] {
+        &self.vertices[..self.len]
+    }
+
+    /// The closest point to the origin, as a position.
+    fn closest(&self) -> Direction2d<N> {
+        self.vertices().iter().zip(self.weights).fold(Direction2d::new(N::zero(), N::zero()), |sum, (vertex, weight)| sum + vertex.w * weight)
+    }
+
+    /// The points of each core which give the closest point.
+    fn closest_points(&self) -> (Point2d<N>, Point2d<N>) {
+        let origin = Point2d::new(N::zero(), N::zero());
+        let (a, b) = self.vertices().iter().zip(self.weights).fold((origin, origin), |(a, b), (vertex, weight)| {
+            (a + (vertex.a - origin) * weight, b + (vertex.b - origin) * weight)
+        });
+        (a, b)
+    }
+
+    /// Cut the simplex down to the fewest vertices whose hull has the same closest point to the origin, and weight
+    /// them.  Returns false if the origin is inside the triangle.
+    fn solve(&mut self) -> bool {
+        match self.len {
+            1 => self.weights[0] = N::one(),
+            2 => self.solve_segment(0, 1),
+            _ => {
+                let [p, q, r] = self.vertices.map(|vertex| vertex.w);
+                let cross = |u: Direction2d<N>, v: Direction2d<N>| u.x * v.y - u.y * v.x;
+                let areas = [cross(q, r), cross(r, p), cross(p, q)];
+                let total = areas[0] + areas[1] + areas[2];
+                if total != N::zero() && areas.iter().all(|&area| area * total >= N::zero()) {
+                    self.weights = areas.map(|area| area / total);
+                    return false;
+                }
+                // Outside, so the closest point is on one of the edges.
+                let mut best: Option<(N, Simplex<N>)> = None;
+                for (i, j) in [(0, 1), (1, 2), (2, 0)] {
+                    let mut edge = *self;
+                    edge.solve_segment(i, j);
+                    let distance = edge.closest().length();
+                    if best.as_ref().is_none_or(|(closest, _)| distance < *closest) {
+                        best = Some((distance, edge));
+                    }
+                }
+                if let Some((_, edge)) = best {
+                    *self = edge;
+                }
+            },
+        }
+        true
+    }
+
+    /// Cut the simplex down to the closest point of the segment between two of its vertices.
+    fn solve_segment(&mut self, i: usize, j: usize) {
+        let (start, end) = (self.vertices[i], self.vertices[j]);
+        let edge = end.w - start.w;
+        let length = edge.dot(&edge);
+        let t = if length > N::zero() { -start.w.dot(&edge) / length } else { N::zero() };
+        if t <= N::zero() {
+            *self = Simplex::new(start);
+        } else if t >= N::one() {
+            *self = Simplex::new(end);
+        } else {
+            self.vertices = [start, end, end];
+            self.weights = [N::one() - t, t, N::zero()];
+            self.len = 2;
+        }
+    }
+}
+
+/// What GJK found.
+enum Cores<N: Float> {
+    /// The cores are apart, and the simplex's closest point is the closest point of the difference.
+    Apart(Simplex<N>),
+    /// The cores overlap or touch, and the simplex contains the origin, or nearly does.
+    Overlapping(Simplex<N>),
+}
+
+/// Run GJK on the shapes' cores.
+fn closest_cores<N: Float, A: SupportMapping<N>, B: SupportMapping<N>>(a: &A, b: &B, tolerance: N) -> Cores<N> {
+    let mut simplex = Simplex::new(Vertex::support(a, b, Direction2d::new(N::one(), N::zero())));
+    for _ in 0..MAX_GJK_ITERATIONS {
+        if !simplex.solve() {
+            return Cores::Overlapping(simplex);
+        }
+        let closest = simplex.closest();
+        let scale = simplex.vertices().iter().fold(N::one(), |scale, vertex| scale.max(vertex.w.length()));
+        let distance = closest.dot(&closest);
+        if distance <= (tolerance * scale).powi(2) {
+            return Cores::Overlapping(simplex);
+        }
+
+        let vertex = Vertex::support(a, b, -closest);
+        // How much closer the new point gets, times the distance.  Stop once that's nothing.
+        if distance - vertex.w.dot(&closest) <= tolerance * distance
+            || simplex.vertices().iter().any(|existing| existing.w == vertex.w) {
+            break;
+        }
+        simplex.vertices[simplex.len] = vertex;
+        simplex.len += 1;
+    }
+    if simplex.solve() { Cores::Apart(simplex) } else { Cores::Overlapping(simplex) }
+}
+
+/// Run EPA from GJK's final simplex, which contains the origin.  Gives the deepest points of each core, the way to
+/// move `b` out, and how far.
+fn expand<N: Float, A: SupportMapping<N>, B: SupportMapping<N>>(a: &A, b: &B, simplex: &Simplex<N>, tolerance: N) -> (Point2d<N>, Point2d<N>, Direction2d<N>, N) {
+    let cross = |u: Direction2d<N>, v: Direction2d<N>| u.x * v.y - u.y * v.x;
+    let scale = simplex.vertices().iter().fold(N::one(), |scale, vertex| scale.max(vertex.w.length()));
+    let (zero, one) = (N::zero(), N::one());
+
+    // Make a triangle around the origin, if GJK stopped with less.  If the difference has no area, the shapes only
+    // touch, across the line it's on.
+    let mut polygon = [simplex.vertices[0]; MAX_EPA_VERTICES];
+    polygon[..simplex.len].copy_from_slice(simplex.vertices());
+    let mut len = simplex.len;
+    if len == 1 {
+        let first = polygon[0];
+        let directions = [Direction2d::new(one, zero), Direction2d::new(zero, one), Direction2d::new(-one, zero), Direction2d::new(zero, -one)];
+        match directions.into_iter().map(|direction| Vertex::support(a, b, direction)).find(|vertex| (vertex.w - first.w).length() > tolerance * scale) {
+            Some(vertex) => {
+                polygon[1] = vertex;
+                len = 2;
+            },
+            None => return (first.a, first.b, Direction2d::new(one, zero), zero),
+        }
+    }
+    if len == 2 {
+        let (start, end) = (polygon[0], polygon[1]);
+        let edge = end.w - start.w;
+        let third = [edge.perpendicular(), -edge.perpendicular()].into_iter()
+            .map(|direction| Vertex::support(a, b, direction))
+            .find(|vertex| cross(edge, vertex.w - start.w).abs() > tolerance * scale * edge.length());
+        match third {
+            Some(vertex) => {
+                polygon[2] = vertex;
+                len = 3;
+            },
+            None => {
+                let t = (-start.w.dot(&edge) / edge.dot(&edge)).max(zero).min(one);
+                let normal = edge.perpendicular().normalized();
+                return (start.a + (end.a - start.a) * t, start.b + (end.b - start.b) * t, normal, zero);
+            },
+        }
+    }
+    if cross(polygon[1].w - polygon[0].w, polygon[2].w - polygon[0].w) < zero {
+        polygon.swap(1, 2);
+    }
+
+    loop {
+        // The edge closest to the origin, and its outward normal.
+        let mut closest = (0, Direction2d::new(one, zero), N::infinity());
+        for i in 0..len {
+            let edge = polygon[(i + 1) % len].w - polygon[i].w;
+            if edge.dot(&edge) == zero {
+                continue;
+            }
+            let normal = Direction2d::new(edge.y, -edge.x).normalized();
+            let distance = normal.dot(&polygon[i].w);
+            if distance < closest.2 {
+                closest = (i, normal, distance);
+            }
+        }
+        let (i, normal, distance) = closest;
+
+        let vertex = Vertex::support(a, b, normal);
+        if len == MAX_EPA_VERTICES || normal.dot(&vertex.w) - distance <= tolerance * scale {
+            let (start, end) = (polygon[i], polygon[(i + 1) % len]);
+            let edge = end.w - start.w;
+            let t = (-start.w.dot(&edge) / edge.dot(&edge)).max(zero).min(one);
+            return (start.a + (end.a - start.a) * t, start.b + (end.b - start.b) * t, -normal, distance.max(zero));
+        }
+        polygon.copy_within(i + 1..len, i + 2);
+        polygon[i + 1] = vertex;
+        len += 1;
+    }
+}
@@ -0,0 +1,207 @@
+use alloc::{collections::VecDeque, vec::Vec};
+
+use core::cmp::Ordering;
+
+use num_traits::{Float, FloatConst};
+
+use crate::{defs::{vector::Vector, bivector::Bivector}, traits::OuterProduct};
+
+use super::{direction2d::Direction2d, line2d::{Line2d, default_tolerance}, point2d::Point2d, polygon2d::Polygon2d, predicates::side_of_line};
+
+// HALF-PLANE INTERSECTION //
+// Each oriented line keeps the half-plane to its left, the same as in `clip`.  The lines are sorted by the angle of
+// their direction, and the boundary is built by walking around them, dropping lines whose corners fall outside the
+// next one.  That's O(n log n) for the sort, and O(n) for the walk.
+// Requires the `alloc` feature.
+
+/// The region left of every line in a set.
+#[derive(Clone, Debug, PartialEq)]
+pub enum HalfPlaneRegion<N: Float> {
+    /// Nothing is left of every line, or what's left has no area (a line or a point).
+    Empty,
+    /// A convex polygon, counterclockwise.
+    Bounded(Polygon2d<N>),
+    /// A region which goes off to infinity.  Its boundary is a chain of lines, counterclockwise, and these are its
+    /// vertices.  The first vertex is the point at infinity (e12 = 0) the chain comes in from, and the last is the
+    /// point at infinity it leaves towards.  The ones between are the meets of consecutive lines, which are points
+    /// at infinity too when those lines are parallel.  With no lines at all, this is empty, and the region is the
+    /// whole plane.
+    Unbounded(Vec<Bivector<N>>),
+}
+
+/// Intersect the half-planes left of each line.
+///
+/// Lines with the same direction (within the default tolerance) are merged, keeping the one further left.  A line
+/// with no direction, such as the line at infinity, either keeps everything or nothing.
+pub fn intersect_half_planes<N: Float + FloatConst>(lines: &[Vector<N>]) -> HalfPlaneRegion<N> {
+    let tolerance = default_tolerance::<N>();
+    let origin = Point2d::new(N::zero(), N::zero()).to_bivector();
+
+    let mut sorted: Vec<(N, Line2d<N>)> = Vec::with_capacity(lines.len());
+    for line in lines {
+        if line.e1 == N::zero() && line.e2 == N::zero() {
+            // `c e0` keeps the plane if c <= 0.
+            if side_of_line(line, &origin) == Ordering::Greater {
+                return HalfPlaneRegion::Empty;
+            }
+            continue;
+        }
+        let line = Line2d::from_vector(line);
+        let direction = line.direction();
+        sorted.push((direction.y.atan2(direction.x), line));
+    }
+    sorted.sort_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+    // Merge parallel lines pointing the same way, keeping whichever is further left.
+    let mut merged: Vec<(N, Line2d<N>)> = Vec::with_capacity(sorted.len());
+    for (angle, line) in sorted {
+        match merged.last_mut() {
+            Some((last_angle, last)) if angle - *last_angle <= tolerance => {
+                if side_of_line(&last.to_vector(), &point_on(&line)) == Ordering::Less {
+                    *last = line;
+                }
+            },
+            _ => merged.push((angle, line)),
+        }
+    }
+    // The first and last can be the same direction, across -π and π.
+    if merged.len() > 1 {
+        let (first_angle, first) = merged[0];
+        let (last_angle, last) = merged[merged.len() - 1];
+        if first_angle + N::TAU() - last_angle <= tolerance {
+            if side_of_line(&first.to_vector(), &point_on(&last)) == Ordering::Less {
+                merged[0] = (first_angle, last);
+            }
+            merged.pop();
+        }
+    }
+
+    // The region is unbounded when there's a gap of at least half a turn between the directions, since it
+    // goes off to infinity in the middle of that gap.
+    let mut widest = (N::zero(), 0);
+    for i in 0..merged.len() {
+        let next = if i + 1 == merged.len() { merged[0].0 + N::TAU() } else { merged[i + 1].0 };
+        let gap = next - merged[i].0;
+        if gap > widest.0 {
+            widest = (gap, i + 1);
+        }
+    }
+    let lines: Vec<Line2d<N>> = merged.into_iter().map(|(_, line)| line).collect();
+
+    if lines.len() < 3 || widest.0 >= N::PI() {
+        let start = widest.1 % lines.len().max(1);
+        let mut chain = lines[start..].to_vec();
+        chain.extend_from_slice(&lines[..start]);
+        unbounded(&chain)
+    } else {
+        bounded(&lines)
+    }
+}
+
+/// The boundary of an unbounded region, from lines sorted by angle which span at most half a turn.
+/// Each line is further counterclockwise than the last, so corners that end up outside the next line are dropped
+/// from the end of the chain.  The first and last lines always stay, since they're the ones going off to infinity.
+fn unbounded<N: Float>(lines: &[Line2d<N>]) -> HalfPlaneRegion<N> {
+    let mut chain: Vec<Line2d<N>> = Vec::with_capacity(lines.len());
+    for line in lines {
+        while chain.len() >= 2 && !is_inside(line, &corner(&chain[chain.len() - 2], &chain[chain.len() - 1])) {
+            chain.pop();
+        }
+        if let Some(last) = chain.last() {
+            // Only the first and last lines can point opposite ways.  They're either a slab, or nothing.
+            if is_antiparallel(last, line) && !is_inside(last, &point_on(line)) {
+                return HalfPlaneRegion::Empty;
+            }
+        }
+        chain.push(*line);
+    }
+
+    let (first, last) = match (chain.first(), chain.last()) {
+        (Some(first), Some(last)) => (first, last),
+        _ => return HalfPlaneRegion::Unbounded(Vec::new()),
+    };
+    let mut vertices = Vec::with_capacity(chain.len() + 1);
+    vertices.push((-first.direction()).to_bivector());
+    for pair in chain.windows(2) {
+        vertices.push(meet(&pair[0], &pair[1]));
+    }
+    vertices.push(last.direction().to_bivector());
+    HalfPlaneRegion::Unbounded(vertices)
+}
+
+/// The boundary of a bounded region, from lines sorted by angle with no gap of half a turn.
+/// The walk can wrap around, so corners are dropped from both ends of the boundary as each line comes in.
+fn bounded<N: Float>(lines: &[Line2d<N>]) -> HalfPlaneRegion<N> {
+    let mut boundary: VecDeque<Line2d<N>> = VecDeque::with_capacity(lines.len());
+    for line in lines {
+        while boundary.len() >= 2 && !is_inside(line, &corner(&boundary[boundary.len() - 2], &boundary[boundary.len() - 1])) {
+            boundary.pop_back();
+        }
+        while boundary.len() >= 2 && !is_inside(line, &corner(&boundary[0], &boundary[1])) {
+            boundary.pop_front();
+        }
+        if let Some(last) = boundary.back() {
+            // Opposite lines only end up next to each other when everything between them was cut away.
+            if is_antiparallel(last, line) {
+                return HalfPlaneRegion::Empty;
+            }
+        }
+        boundary.push_back(*line);
+    }
+    // The last lines can cut off the first corners, and the other way around.
+    while boundary.len() >= 3 && !is_inside(&boundary[0], &corner(&boundary[boundary.len() - 2], &boundary[boundary.len() - 1])) {
+        boundary.pop_back();
+    }
+    while boundary.len() >= 3 && !is_inside(&boundary[boundary.len() - 1], &corner(&boundary[0], &boundary[1])) {
+        boundary.pop_front();
+    }
+    if boundary.len() < 3 {
+        return HalfPlaneRegion::Empty;
+    }
+
+    let n = boundary.len();
+    let mut vertices = Vec::with_capacity(n);
+    for i in 0..n {
+        let vertex = meet(&boundary[i], &boundary[(i + 1) % n]);
+        match Point2d::try_from_bivector(&vertex, N::zero()) {
+            Some(point) => vertices.push(point),
+            None => return HalfPlaneRegion::Empty,
+        }
+    }
+    HalfPlaneRegion::Bounded(Polygon2d::new(vertices))
+}
+
+/// Whether a point is strictly left of a line.  Points on the line count as outside, so lines which only touch the
+/// region at a corner get dropped.
+fn is_inside<N: Float>(line: &Line2d<N>, point: &Bivector<N>) -> bool {
+    side_of_line(&line.to_vector(), point) == Ordering::Less
+}
+
+/// The corner between two consecutive lines, with a positive weight.
+fn corner<N: Float>(l1: &Line2d<N>, l2: &Line2d<N>) -> Bivector<N> {
+    let meet = l1.to_vector().wedge(&l2.to_vector());
+    if meet.e12 < N::zero() { meet * -N::one() } else { meet }
+}
+
+/// The meet of two consecutive lines, as a vertex.  Finite points are normalized, and parallel lines meet at the
+/// point at infinity the first one is heading towards.
+fn meet<N: Float>(l1: &Line2d<N>, l2: &Line2d<N>) -> Bivector<N> {
+    let meet = corner(l1, l2);
+    match Point2d::try_from_bivector(&meet, N::zero()) {
+        Some(point) => point.to_bivector(),
+        None => l1.direction().to_bivector(),
+    }
+}
+
+/// Some point on a line.  The closest one to the origin.
+fn point_on<N: Float>(line: &Line2d<N>) -> Bivector<N> {
+    let normal: Direction2d<N> = line.normal();
+    (Point2d::new(N::zero(), N::zero()) + normal * line.offset()).to_bivector()
+}
+
+/// Whether two lines point opposite ways, within the default tolerance, the same as when merging lines which point
+/// the same way.  The directions are normalized, so their cross product is the sine of the angle between them.
+fn is_antiparallel<N: Float>(l1: &Line2d<N>, l2: &Line2d<N>) -> bool {
+    let (d1, d2) = (l1.direction(), l2.direction());
+    (d1.x * d2.y - d1.y * d2.x).abs() <= default_tolerance() && d1.dot(&d2) < N::zero()
+}
@@ -0,0 +1,63 @@
+use alloc::vec::Vec;
+
+use num_traits::Float;
+
+use super::{ordering::sort_points, point2d::Point2d, polygon2d::Polygon2d, predicates::{orient2d, Orientation}};
+
+// CONVEX HULLS //
+// Andrew's monotone chain.  The points are sorted left to right, and the lower and upper halves of the hull are built
+// by walking along them, dropping any point which doesn't make a counterclockwise turn.  The turns are the sign of
+// the meet of a join with the next point, computed exactly by `predicates::orient2d`.
+// Requires the `alloc` feature.
+
+/// The indices of the points on the convex hull, counterclockwise, starting from the leftmost (then lowest) point.
+///
+/// The result is deterministic:
+///  * Duplicate points only appear once, as the one with the lowest index.
+///  * Points in the middle of a hull edge (collinear) are left out, so every corner is a real turn.
+///  * If every point is the same, the hull is that one point.  If they're all on a line, it's the two ends.
+pub fn convex_hull_indices<N: Float>(points: &[Point2d<N>]) -> Vec<usize> {
+    let (order, _) = sort_points(points);
+
+    if order.len() < 3 {
+        return order;
+    }
+
+    let mut hull: Vec<usize> = Vec::with_capacity(order.len() + 1);
+    // The lower half, left to right.
+    for &i in order.iter() {
+        while hull.len() >= 2 && !turns_left(points, &hull, i) {
+            hull.pop();
+        }
+        hull.push(i);
+    }
+    // The upper half, right to left.  The rightmost point is already in.
+    let lower = hull.len() + 1;
+    for &i in order.iter().rev().skip(1) {
+        while hull.len() >= lower && !turns_left(points, &hull, i) {
+            hull.pop();
+        }
+        hull.push(i);
+    }
+    // The last point is the first one again.
+    hull.pop();
+    hull
+}
+
+/// The convex hull of some points, as a counterclockwise polygon.  See `convex_hull_indices`.
+pub fn convex_hull<N: Float>(points: &[Point2d<N>]) -> Polygon2d<N> {
+    Polygon2d::new(
+        convex_hull_indices(points)
+            .into_iter()
+            .map(|i| points[i])
+            .collect()
+    )
+}
+
+/// Whether going from the last two points of the hull to `next` is a strict counterclockwise turn.
+/// This uses the exact `orient2d`, so nearly collinear points can't make the hull inconsistent.
+fn turns_left<N: Float>(points: &[Point2d<N>], hull: &[usize], next: usize) -> bool {
+    let a = &points[hull[hull.len() - 2]];
+    let b = &points[hull[hull.len() - 1]];
+    orient2d(a, b, &points[next]) == Orientation::CounterClockwise
+}
@@ -0,0 +1,194 @@
+use num_traits::Float;
+
+use crate::{defs::{vector::Vector, bivector::Bivector}, traits::{OuterProduct, RegressiveProduct, MagnitudeSqr, Normalize}};
+
+use super::{angle::Angle, direction2d::Direction2d, point2d::Point2d, transformations::RigidTransformation};
+
+/// In 2d pga, a line is a vector.  This is a normalized line `ax + by + c = 0` with `a² + b² = 1`, which can be
+/// converted to and from the vector struct, as a convenient handle.  The line at infinity is the exception, with
+/// `a = b = 0`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Line2d<N: Float> {
+    a: N,
+    b: N,
+    c: N,
+}
+
+/// The result of intersecting two lines.
+#[derive(Clone, Debug, PartialEq)]
+pub enum LineIntersection<N: Float> {
+    /// The lines cross at a single finite point.
+    Point(Point2d<N>),
+    /// The lines are parallel.  They meet at this point at infinity (e12 = 0), which is their shared direction.
+    Parallel(Bivector<N>),
+    /// The lines are the same line, or one of them is degenerate, so there's no single meeting point.
+    Coincident,
+}
+
+/// The tolerance used when none is provided.  The square root of machine epsilon.
+pub fn default_tolerance<N: Float>() -> N {
+    N::epsilon().sqrt()
+}
+
+// Constructors and conversions for line 2d //
+
+impl<N: Float> Line2d<N> {
+    /// Build a line from a vector, normalizing it.  The line at infinity (`e1 = e2 = 0`) has no normal to normalize,
+    /// so it's kept as it is, rather than filled with NaN.
+    pub fn from_vector(vector: &Vector<N>) -> Line2d<N> {
+        if vector.e1 == N::zero() && vector.e2 == N::zero() {
+            return Line2d {
+                a: N::zero(),
+                b: N::zero(),
+                c: vector.e0,
+            };
+        }
+        let normalized = vector.normalized();
+        Line2d {
+            a: normalized.e1,
+            b: normalized.e2,
+            c: normalized.e0,
+        }
+    }
+
+    /// Cast your line to a vector.
+    pub fn to_vector(&self) -> Vector<N> {
+        Vector {
+            e0: self.c,
+            e1: self.a,
+            e2: self.b,
+        }
+    }
+
+    /// The line `y = mx + k`, pointing towards +x.
+    pub fn from_slope_intercept(slope: N, intercept: N) -> Line2d<N> {
+        Line2d::from_vector(&Vector {
+            e0: intercept,
+            e1: slope,
+            e2: -N::one(),
+        })
+    }
+
+    /// The line through a point, pointing in a direction.  This is the join of the point and the direction's
+    /// point at infinity.
+    pub fn from_point_direction(point: Point2d<N>, direction: Direction2d<N>) -> Line2d<N> {
+        Line2d::from_vector(&point.to_bivector().regressive(&direction.to_bivector()))
+    }
+
+    /// The line with the provided normal, at a signed distance `offset` from the origin along that normal.
+    pub fn from_normal_offset(normal: Direction2d<N>, offset: N) -> Line2d<N> {
+        let normal = normal.normalized();
+        Line2d {
+            a: normal.x,
+            b: normal.y,
+            c: -offset,
+        }
+    }
+
+    /// The line whose normal points at `angle` from the x axis, at a signed distance `offset` from the origin
+    /// along that normal.
+    pub fn from_angle_offset(angle: Angle<N>, offset: N) -> Line2d<N> {
+        let radians = angle.get_radians();
+        Line2d {
+            a: radians.cos(),
+            b: radians.sin(),
+            c: -offset,
+        }
+    }
+
+    /// The line through two points, pointing from the first to the second.
+    /// See `Point2d::line_between_points`.
+    pub fn from_points(p1: Point2d<N>, p2: Point2d<N>) -> Line2d<N> {
+        Line2d::from_vector(&Point2d::line_between_points(p1, p2))
+    }
+}
+
+// Getters //
+
+impl<N: Float> Line2d<N> {
+    /// The unit normal of this line.  Points on this side of the line have a positive signed distance.
+    pub fn normal(&self) -> Direction2d<N> {
+        Direction2d::new(self.a, self.b)
+    }
+
+    /// The unit direction of this line.  This is the meet of the line with the line at infinity, and is the normal
+    /// rotated a quarter turn counterclockwise.
+    pub fn direction(&self) -> Direction2d<N> {
+        Direction2d::from_bivector(&Vector { e0: N::one(), e1: N::zero(), e2: N::zero() }.wedge(&self.to_vector()))
+    }
+
+    /// The signed distance from the origin to this line, along its normal.
+    pub fn offset(&self) -> N {
+        -self.c
+    }
+
+    /// The angle of this line's normal, measured from the x axis.
+    pub fn angle(&self) -> Angle<N> {
+        Angle::from_radians(self.b.atan2(self.a))
+    }
+}
+
+impl<N: Float> From<Vector<N>> for Line2d<N> {
+    fn from(vector: Vector<N>) -> Self {
+        Line2d::from_vector(&vector)
+    }
+}
+
+impl<N: Float> From<Line2d<N>> for Vector<N> {
+    fn from(line: Line2d<N>) -> Self {
+        line.to_vector()
+    }
+}
+
+/// Anything which can transform a vector can transform a line.
+impl<N: Float, T: RigidTransformation<Vector<N>>> RigidTransformation<Line2d<N>> for T {
+    fn apply(&self, target: &Line2d<N>) -> Line2d<N> {
+        Line2d::from_vector(&self.apply(&target.to_vector()))
+    }
+}
+
+// Intersections //
+
+impl<N: Float> Line2d<N> {
+    /// Intersect two lines with the default tolerance.  See `intersect_with_tolerance`.
+    pub fn intersect(&self, other: &Line2d<N>) -> LineIntersection<N> {
+        self.intersect_with_tolerance(other, default_tolerance())
+    }
+
+    /// Intersect two lines using the meet (wedge product).
+    /// Since both lines are normalized, the e12 part of the meet is the sine of the angle between them, and for
+    /// parallel lines the ideal part of the meet is the distance between them.  Both are compared against `tolerance`.
+    pub fn intersect_with_tolerance(&self, other: &Line2d<N>, tolerance: N) -> LineIntersection<N> {
+        self.to_vector().intersect_with_tolerance(&other.to_vector(), tolerance)
+    }
+}
+
+impl<N: Float> Vector<N> {
+    /// Intersect two lines with the default tolerance.  See `intersect_with_tolerance`.
+    pub fn intersect(&self, other: &Vector<N>) -> LineIntersection<N> {
+        self.intersect_with_tolerance(other, default_tolerance())
+    }
+
+    /// Intersect two lines using the meet (wedge product), interpreting the resulting bivector.
+    /// The lines don't need to be normalized.  A line with no normal (such as the line at infinity) is treated
+    /// as degenerate.
+    pub fn intersect_with_tolerance(&self, other: &Vector<N>, tolerance: N) -> LineIntersection<N> {
+        let norm_self = self.magnitude_sqr().sqrt();
+        let norm_other = other.magnitude_sqr().sqrt();
+        if norm_self <= tolerance || norm_other <= tolerance {
+            return LineIntersection::Coincident;
+        }
+
+        let meet = self.wedge(other) * (N::one() / (norm_self * norm_other));
+        if let Some(point) = Point2d::try_from_bivector(&meet, tolerance) {
+            return LineIntersection::Point(point);
+        }
+        if meet.ideal_norm() <= tolerance {
+            return LineIntersection::Coincident;
+        }
+
+        // The meet of the first line with the line at infinity is its direction.
+        let direction = Vector { e0: N::one(), e1: N::zero(), e2: N::zero() }.wedge(self);
+        LineIntersection::Parallel(direction * (N::one() / norm_self))
+    }
+}
@@ -0,0 +1,69 @@
+use num_traits::Float;
+
+use crate::{defs::{vector::Vector, bivector::Bivector}, traits::{OuterProduct, RegressiveProduct, Contraction, MagnitudeSqr, Normalize}};
+
+use super::{angle::Angle, point2d::Point2d};
+
+// DISTANCES AND ANGLES //
+// Everything here is done with the products, not by converting to cartesian coordinates.
+// Lines and points don't need to be normalized ahead of time; the norms get divided out.
+
+impl<N: Float> Vector<N> {
+    /// The signed distance from this line to a point.  This is the meet of the two (a trivector), divided by their norms.
+    /// Positive on the side of the line the normal (e1, e2) points towards.  That is, where `ax+by+c > 0`.
+    pub fn distance_to_point(&self, point: &Bivector<N>) -> N {
+        self.wedge(point).e012 / (self.magnitude_sqr().sqrt() * point.e12)
+    }
+
+    /// The signed angle from this line to another, counterclockwise, in the range (-π, π].
+    /// The cosine comes from the inner product, and the sine from the e12 part of the meet.
+    pub fn angle_to(&self, other: &Vector<N>) -> Angle<N> {
+        let cos = self.inner(other);
+        let sin = self.wedge(other).e12;
+        Angle::from_radians(sin.atan2(cos))
+    }
+
+    /// The distance between this line and a parallel line.
+    /// The meet of two parallel lines is a point at infinity, whose (ideal) norm is the distance between them.
+    /// If the lines aren't parallel, the result is meaningless.
+    pub fn distance_to_parallel(&self, other: &Vector<N>) -> N {
+        let a = self.normalized();
+        let mut b = other.normalized();
+        // Lines with opposite orientations need to be lined up first.
+        if a.inner(&b) < N::zero() {
+            b = b * -N::one();
+        }
+        a.wedge(&b).ideal_norm()
+    }
+}
+
+impl<N: Float> Bivector<N> {
+    /// The euclidean distance between two points.  The join of two points is a line, whose norm is the distance
+    /// between them.
+    pub fn distance_to(&self, other: &Bivector<N>) -> N {
+        self.regressive(other).magnitude_sqr().sqrt() / (self.e12 * other.e12).abs()
+    }
+
+    /// The signed distance from this point to a line.  See `Vector::distance_to_point`.
+    pub fn distance_to_line(&self, line: &Vector<N>) -> N {
+        line.distance_to_point(self)
+    }
+
+    /// The norm of the ideal (e01, e20) part of this bivector.  For a point at infinity, this is its length as a
+    /// direction.
+    pub fn ideal_norm(&self) -> N {
+        (self.e01 * self.e01 + self.e20 * self.e20).sqrt()
+    }
+}
+
+impl<N: Float> Point2d<N> {
+    /// The euclidean distance between two points.
+    pub fn distance_to(&self, other: &Point2d<N>) -> N {
+        self.to_bivector().distance_to(&other.to_bivector())
+    }
+
+    /// The signed distance from this point to a line.  See `Vector::distance_to_point`.
+    pub fn distance_to_line(&self, line: &Vector<N>) -> N {
+        line.distance_to_point(&self.to_bivector())
+    }
+}
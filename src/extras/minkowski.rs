@@ -0,0 +1,183 @@
+use alloc::vec::Vec;
+
+use core::cmp::Ordering;
+
+use num_traits::Float;
+
+use super::{boolean::{union, PolygonWithHoles}, delaunay::{triangulate_polygon, TriangulationError}, ordering::find, point2d::Point2d, polygon2d::{Polygon2d, Winding}, predicates::{orient2d, Orientation}};
+
+// MINKOWSKI SUMS //
+// The Minkowski sum of two shapes is every point of one plus every point of the other.  For convex polygons, its
+// edges are the edges of both, sorted by angle, so it's built by walking around both at once from their lowest
+// vertices, always taking whichever edge turns least.  That's O(n + m).
+// The difference is the sum with the second shape turned half way around the origin, which is the set of offsets
+// where the shapes overlap.
+// Anything else is cut into convex pieces, and the sums of every pair of pieces are merged with `boolean::union`.
+// Requires the `alloc` feature.
+
+/// The Minkowski sum of two convex polygons, counterclockwise.  Either can go either way around.
+///
+/// Parallel edges are merged, so the sum has at most `n + m` vertices.  It starts from the lowest (then leftmost)
+/// vertex.  A polygon with one vertex just moves the other, and one with two vertices is a segment, which sweeps the
+/// other along it.  The result is empty if either polygon is.
+pub fn minkowski_sum<N: Float>(a: &Polygon2d<N>, b: &Polygon2d<N>) -> Polygon2d<N> {
+    if a.is_empty() || b.is_empty() {
+        return Polygon2d::new(Vec::new());
+    }
+    let a = from_lowest(a);
+    let b = from_lowest(b);
+    let (n, m) = (a.len(), b.len());
+    let edge = |polygon: &[Point2d<N>], i: usize| polygon[(i + 1) % polygon.len()] - polygon[i % polygon.len()];
+
+    let mut sum: Vec<Point2d<N>> = Vec::with_capacity(n + m);
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        sum.push(a[i % n] + (b[j % m] - Point2d::new(N::zero(), N::zero())));
+        let (p, q) = (edge(&a, i), edge(&b, j));
+        let turn = p.x * q.y - p.y * q.x;
+        // Whichever edge is further clockwise goes first, or both if they're parallel.
+        if turn >= N::zero() && i < n {
+            i += 1;
+        }
+        if turn <= N::zero() && j < m {
+            j += 1;
+        }
+    }
+    sum.dedup();
+    while sum.len() > 1 && sum.first() == sum.last() {
+        sum.pop();
+    }
+    Polygon2d::new(sum)
+}
+
+/// The Minkowski difference of two convex polygons: the sum of `a` and `b` turned half way around the origin.
+///
+/// This is every offset `p - q` between a point `p` in `a` and a point `q` in `b`, so `b` moved by some offset
+/// overlaps `a` exactly when the offset is in the difference.  For pathfinding, `a` is an obstacle and `b` is the
+/// shape of what's moving, relative to its origin, and the difference is where its origin can't go.
+pub fn minkowski_difference<N: Float>(a: &Polygon2d<N>, b: &Polygon2d<N>) -> Polygon2d<N> {
+    minkowski_sum(a, &reflected(b))
+}
+
+/// The Minkowski sum of two polygons which don't have to be convex, and can have holes.
+///
+/// Both are cut into convex pieces with `convex_decomposition`, every pair of pieces is summed, and the sums are
+/// merged.  That's O(n² m²) at worst, so prefer `minkowski_sum` for convex polygons.
+pub fn minkowski_sum_general<N: Float>(a: &PolygonWithHoles<N>, b: &PolygonWithHoles<N>) -> Result<Vec<PolygonWithHoles<N>>, TriangulationError> {
+    let a_pieces = convex_decomposition(a)?;
+    let b_pieces = convex_decomposition(b)?;
+    let mut sums: Vec<Vec<PolygonWithHoles<N>>> = Vec::with_capacity(a_pieces.len() * b_pieces.len());
+    for a_piece in &a_pieces {
+        for b_piece in &b_pieces {
+            sums.push(Vec::from([PolygonWithHoles::from(minkowski_sum(a_piece, b_piece))]));
+        }
+    }
+
+    // Merged in pairs, so each union is between two results of about the same size.
+    while sums.len() > 1 {
+        let mut merged: Vec<Vec<PolygonWithHoles<N>>> = Vec::with_capacity(sums.len().div_ceil(2));
+        let mut pairs = sums.chunks_exact(2);
+        for pair in pairs.by_ref() {
+            merged.push(union(&pair[0], &pair[1]));
+        }
+        merged.extend(pairs.remainder().iter().cloned());
+        sums = merged;
+    }
+    Ok(sums.pop().unwrap_or_default())
+}
+
+/// The Minkowski difference of two polygons which don't have to be convex.  See `minkowski_difference` and
+/// `minkowski_sum_general`.
+pub fn minkowski_difference_general<N: Float>(a: &PolygonWithHoles<N>, b: &PolygonWithHoles<N>) -> Result<Vec<PolygonWithHoles<N>>, TriangulationError> {
+    let b = PolygonWithHoles::new(reflected(b.exterior()), b.holes().iter().map(reflected).collect());
+    minkowski_sum_general(a, &b)
+}
+
+/// Cut a polygon into convex pieces, counterclockwise.
+///
+/// The polygon is triangulated (see `delaunay::triangulate_polygon`), then neighbouring pieces are merged wherever
+/// they stay convex (Hertel and Mehlhorn's algorithm).  That's at most four times as many pieces as the fewest
+/// possible.
+pub fn convex_decomposition<N: Float>(polygon: &PolygonWithHoles<N>) -> Result<Vec<Polygon2d<N>>, TriangulationError> {
+    let triangulation = triangulate_polygon(polygon)?;
+    let points = triangulation.points();
+
+    // Each piece is a loop of point indices, kept by the first of its triangles.
+    let mut pieces: Vec<Vec<usize>> = triangulation.triangles().iter().map(|triangle| triangle.to_vec()).collect();
+    let mut parent: Vec<usize> = (0..pieces.len()).collect();
+    for (t, triangle) in triangulation.triangles().iter().enumerate() {
+        for (edge, neighbour) in triangulation.neighbours()[t].iter().enumerate() {
+            let Some(other) = *neighbour else {
+                continue;
+            };
+            if other < t || triangulation.is_constrained(t, edge) {
+                continue;
+            }
+            let (p, q) = (find(&mut parent, t), find(&mut parent, other));
+            if p == q {
+                // Around a hole, pieces can meet on both sides.  Merging again would go around the hole.
+                continue;
+            }
+            let (u, v) = (triangle[(edge + 1) % 3], triangle[(edge + 2) % 3]);
+            if let Some(merged) = merge(points, &pieces[p], &pieces[q], u, v) {
+                pieces[p] = merged;
+                pieces[q].clear();
+                parent[q] = p;
+            }
+        }
+    }
+
+    Ok(pieces.into_iter()
+        .filter(|piece| !piece.is_empty())
+        .map(|piece| Polygon2d::new(piece.into_iter().map(|i| points[i]).collect()))
+        .collect())
+}
+
+// Helpers //
+
+/// The vertices, counterclockwise, starting from the lowest (then leftmost) one.  Repeated vertices are dropped, since
+/// an edge with no length has no angle to be merged by.
+fn from_lowest<N: Float>(polygon: &Polygon2d<N>) -> Vec<Point2d<N>> {
+    let mut vertices: Vec<Point2d<N>> = match polygon.winding() {
+        Winding::Clockwise => polygon.reversed().into_vertices(),
+        _ => polygon.vertices().to_vec(),
+    };
+    vertices.dedup();
+    while vertices.len() > 1 && vertices.first() == vertices.last() {
+        vertices.pop();
+    }
+    let lowest = (0..vertices.len())
+        .min_by(|&i, &j| {
+            let (p, q) = (vertices[i], vertices[j]);
+            p.y.partial_cmp(&q.y).unwrap_or(Ordering::Equal).then(p.x.partial_cmp(&q.x).unwrap_or(Ordering::Equal))
+        })
+        .unwrap_or(0);
+    vertices.rotate_left(lowest);
+    vertices
+}
+
+/// A polygon turned half way around the origin.  It still goes the same way around.
+fn reflected<N: Float>(polygon: &Polygon2d<N>) -> Polygon2d<N> {
+    Polygon2d::new(polygon.vertices().iter().map(|vertex| Point2d::new(-vertex.x, -vertex.y)).collect())
+}
+
+/// Two convex pieces joined along the edge from `u` to `v` in the first (and `v` to `u` in the second), if that's
+/// still convex.  The only corners which change are at `u` and `v`.
+fn merge<N: Float>(points: &[Point2d<N>], first: &[usize], second: &[usize], u: usize, v: usize) -> Option<Vec<usize>> {
+    // The first from v around to u, then the second from u around to v, without repeating u or v.
+    let start = first.iter().position(|&w| w == v)?;
+    let mut merged: Vec<usize> = first.iter().cycle().skip(start).take(first.len()).copied().collect();
+    let start = second.iter().position(|&w| w == u)?;
+    merged.extend(second.iter().cycle().skip(start + 1).take(second.len() - 2));
+
+    let convex_at = |i: usize| {
+        let n = merged.len();
+        let (previous, corner, next) = (merged[(i + n - 1) % n], merged[i], merged[(i + 1) % n]);
+        orient2d(&points[previous], &points[corner], &points[next]) != Orientation::Clockwise
+    };
+    if convex_at(0) && convex_at(first.len() - 1) {
+        Some(merged)
+    } else {
+        None
+    }
+}
@@ -1,3 +1,38 @@
 pub mod point2d;
+pub mod direction2d;
 pub mod transformations;
-pub mod angle;
\ No newline at end of file
+pub mod angle;
+pub mod metric;
+pub mod projection;
+pub mod line2d;
+pub mod segment2d;
+pub mod ray2d;
+pub mod circle2d;
+pub mod arc2d;
+pub mod capsule2d;
+pub mod predicates;
+pub mod clip;
+#[cfg(feature = "alloc")]
+pub mod polygon2d;
+#[cfg(feature = "alloc")]
+mod ordering;
+#[cfg(feature = "alloc")]
+pub mod hull;
+#[cfg(feature = "alloc")]
+pub mod half_plane;
+
+#[cfg(feature = "alloc")]
+pub mod boolean;
+#[cfg(feature = "alloc")]
+pub mod delaunay;
+#[cfg(feature = "alloc")]
+pub mod voronoi;
+#[cfg(feature = "alloc")]
+pub mod arrangement;
+#[cfg(feature = "alloc")]
+pub mod minkowski;
+pub mod gjk;
+#[cfg(feature = "alloc")]
+pub mod sat;
+#[cfg(feature = "alloc")]
+pub mod raycast;
\ No newline at end of file
@@ -0,0 +1,49 @@
+use alloc::vec::Vec;
+
+use core::cmp::Ordering;
+
+use num_traits::Float;
+
+use super::point2d::Point2d;
+
+// ORDERING //
+// Sweeps over points, like the convex hull and the Delaunay triangulation, visit them left to right, then bottom to
+// top.  They all sort the same way and keep the same one of a set of duplicates (the one with the lowest index), so
+// their results agree with each other.
+// Triangles which are merged together, such as the ones around one Voronoi vertex or in one convex piece, are kept
+// in groups by a union-find.
+// Requires the `alloc` feature.
+
+/// The points without duplicates, left to right, then bottom to top, and the lowest index of a point equal to each
+/// point.
+pub(crate) fn sort_points<N: Float>(points: &[Point2d<N>]) -> (Vec<usize>, Vec<usize>) {
+    let mut order: Vec<usize> = (0..points.len()).collect();
+    // A stable sort, so duplicates stay in index order.
+    order.sort_by(|&i, &j| compare(&points[i], &points[j]));
+    let mut canonical: Vec<usize> = (0..points.len()).collect();
+    for pair in order.windows(2) {
+        if points[pair[0]] == points[pair[1]] {
+            canonical[pair[1]] = canonical[pair[0]];
+        }
+    }
+    order.dedup_by(|&mut later, &mut earlier| points[later] == points[earlier]);
+    (order, canonical)
+}
+
+/// The group something is in, where `parent` links each member towards the one representing its group, halving the
+/// path on the way.
+pub(crate) fn find(parent: &mut [usize], mut t: usize) -> usize {
+    while parent[t] != t {
+        parent[t] = parent[parent[t]];
+        t = parent[t];
+    }
+    t
+}
+
+/// Left to right, then bottom to top.  NaN coordinates compare as equal, so they won't panic, but the results won't
+/// make any sense.
+pub(crate) fn compare<N: Float>(a: &Point2d<N>, b: &Point2d<N>) -> Ordering {
+    a.x.partial_cmp(&b.x)
+        .unwrap_or(Ordering::Equal)
+        .then(a.y.partial_cmp(&b.y).unwrap_or(Ordering::Equal))
+}
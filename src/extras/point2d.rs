@@ -1,9 +1,11 @@
-use std::{fmt::Display, ops::Add};
+use core::{fmt::Display, ops::Add};
 
 use num_traits::Float;
 
 use crate::{defs::{bivector::Bivector, vector::Vector}, traits::RegressiveProduct};
 
+use super::transformations::RigidTransformation;
+
 /// In 2d pga, a point is a bivector.  This will be a simple eucludian point that
 /// can be converted to and from the bivector struct, as a convenient handle.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -16,7 +18,8 @@ pub struct Point2d<N: Float> {
 
 impl<N: Float> Point2d<N> {
     /// Create a new point in Eucludian space.
-    pub fn new(x: N, y: N) -> Point2d<N> {
+    /// This is a `const fn`, so it can be used to build `const` and `static` points.
+    pub const fn new(x: N, y: N) -> Point2d<N> {
         Point2d {x, y}
     }
 
@@ -37,6 +40,15 @@ impl<N: Float> Point2d<N> {
             y: bivector.e01/bivector.e12,
         }
     }
+
+    /// Build a 2d point from a bivector, unless it is (within `tolerance`) a point at infinity.
+    /// `from_bivector` will happily divide by zero; this won't.
+    pub fn try_from_bivector(bivector: &Bivector<N>, tolerance: N) -> Option<Point2d<N>> {
+        if bivector.e12.abs() <= tolerance {
+            return None;
+        }
+        Some(Point2d::from_bivector(bivector))
+    }
 }
 
 // Some magic pga utilities!
@@ -54,11 +66,18 @@ impl<N: Float> Point2d<N> {
 // Traits
 
 impl<N: Float+Display> Display for Point2d<N> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "[{}, {}]", self.x, self.y)
     }
 }
 
+/// Anything which can transform a bivector can transform a point.
+impl<N: Float, T: RigidTransformation<Bivector<N>>> RigidTransformation<Point2d<N>> for T {
+    fn apply(&self, target: &Point2d<N>) -> Point2d<N> {
+        Point2d::from_bivector(&self.apply(&target.to_bivector()))
+    }
+}
+
 /// Addition between two points is a common opertion.
 impl<N: Float> Add<Point2d<N>> for Point2d<N> {
     type Output = Self;
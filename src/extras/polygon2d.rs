@@ -0,0 +1,230 @@
+use alloc::vec::Vec;
+
+use num_traits::{Float, FloatConst};
+
+use crate::{defs::{vector::Vector, bivector::Bivector}, traits::{OuterProduct, MagnitudeSqr}};
+
+use super::{line2d::default_tolerance, point2d::Point2d, predicates::{orient2d, Orientation}, segment2d::Segment2d, transformations::RigidTransformation};
+
+/// A polygon, as a closed loop of points.  The last point connects back to the first, so it shouldn't be repeated.
+/// Requires the `alloc` feature.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Polygon2d<N: Float> {
+    vertices: Vec<Point2d<N>>,
+}
+
+/// Which way a polygon's vertices go around.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Winding {
+    CounterClockwise,
+    Clockwise,
+    /// The polygon has no area, so it doesn't go either way.
+    Degenerate,
+}
+
+/// How to decide whether a point is inside a polygon which overlaps itself.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FillRule {
+    /// Inside if the polygon goes around the point an odd number of times.
+    EvenOdd,
+    /// Inside if the polygon goes around the point at all.
+    NonZero,
+}
+
+// Constructors and conversions for polygon 2d //
+
+impl<N: Float> Polygon2d<N> {
+    /// Create a polygon from its vertices, in order.
+    pub fn new(vertices: Vec<Point2d<N>>) -> Polygon2d<N> {
+        Polygon2d { vertices }
+    }
+
+    /// Create a polygon by copying vertices out of a slice.
+    pub fn from_slice(vertices: &[Point2d<N>]) -> Polygon2d<N> {
+        Polygon2d { vertices: vertices.to_vec() }
+    }
+
+    /// The vertices of this polygon, in order.
+    pub fn vertices(&self) -> &[Point2d<N>] {
+        &self.vertices
+    }
+
+    /// Take the vertices back out of this polygon.
+    pub fn into_vertices(self) -> Vec<Point2d<N>> {
+        self.vertices
+    }
+
+    /// The number of vertices (and edges).
+    pub fn len(&self) -> usize {
+        self.vertices.len()
+    }
+
+    /// Whether this polygon has no vertices.
+    pub fn is_empty(&self) -> bool {
+        self.vertices.is_empty()
+    }
+
+    /// The same polygon, with its vertices going around the other way.
+    pub fn reversed(&self) -> Polygon2d<N> {
+        let mut vertices = self.vertices.clone();
+        vertices.reverse();
+        Polygon2d { vertices }
+    }
+
+    /// The edges of this polygon, as segments from each vertex to the next.
+    pub fn edges(&self) -> impl Iterator<Item = Segment2d<N>> + '_ {
+        let n = self.vertices.len();
+        (0..n).map(move |i| Segment2d::new(self.vertices[i], self.vertices[(i + 1) % n]))
+    }
+
+    /// The lines through each edge, as vectors.  Each is the join of a vertex with the next, so it points along the
+    /// edge, and its norm is the edge's length.  For a counterclockwise polygon, the normals point outwards.
+    pub fn edge_lines(&self) -> impl Iterator<Item = Vector<N>> + '_ {
+        self.edges().map(|edge| edge.to_vector())
+    }
+}
+
+// Measurements //
+
+impl<N: Float> Polygon2d<N> {
+    /// The signed area of this polygon.  Positive when it's counterclockwise.
+    ///
+    /// Each edge's join is a line whose e0 part is the (negative) doubled area of the triangle from the origin to that
+    /// edge, so the area is a sum over the joins.
+    pub fn signed_area(&self) -> N {
+        let half = N::from(0.5).unwrap();
+        self.edge_lines()
+            .fold(N::zero(), |sum, line| sum - line.e0)
+            * half
+    }
+
+    /// The area of this polygon.
+    pub fn area(&self) -> N {
+        self.signed_area().abs()
+    }
+
+    /// The centroid (center of mass) of the area of this polygon, or `None` if it has no area.
+    ///
+    /// This is the sum of the triangles from the origin to each edge.  Each triangle's vertices are added as
+    /// bivectors, weighted by the triangle's signed area, and normalizing the total divides out the weights.
+    pub fn centroid(&self) -> Option<Point2d<N>> {
+        let total = self.edges()
+            .fold(Bivector::zero(), |sum, edge| {
+                let weight = -edge.to_vector().e0;
+                let (a, b) = (edge.start.to_bivector(), edge.end.to_bivector());
+                // The origin is e12, so it only adds to the weight.
+                Bivector {
+                    e01: sum.e01 + (a.e01 + b.e01) * weight,
+                    e20: sum.e20 + (a.e20 + b.e20) * weight,
+                    e12: sum.e12 + (a.e12 + b.e12 + N::one()) * weight,
+                }
+            });
+        Point2d::try_from_bivector(&total, N::zero())
+    }
+
+    /// The total length of the edges.  Each edge's length is the norm of its join.
+    pub fn perimeter(&self) -> N {
+        self.edge_lines()
+            .fold(N::zero(), |sum, line| sum + line.magnitude_sqr().sqrt())
+    }
+
+    /// Which way this polygon goes around, from the sign of its area.
+    pub fn winding(&self) -> Winding {
+        let area = self.signed_area();
+        if area > N::zero() {
+            Winding::CounterClockwise
+        } else if area < N::zero() {
+            Winding::Clockwise
+        } else {
+            Winding::Degenerate
+        }
+    }
+}
+
+impl<N: Float + FloatConst> Polygon2d<N> {
+    /// Whether this polygon is convex, with the default tolerance.  See `is_convex_with_tolerance`.
+    pub fn is_convex(&self) -> bool {
+        self.is_convex_with_tolerance(default_tolerance())
+    }
+
+    /// Whether this polygon is convex.  Every corner has to turn the same way, which is the sign of the meet of one
+    /// edge's line with the next vertex.  Corners within `tolerance` of straight are ignored.
+    /// The turns also have to add up to a single full turn, which rules out stars that loop around more than once.
+    pub fn is_convex_with_tolerance(&self, tolerance: N) -> bool {
+        let n = self.vertices.len();
+        if n < 3 {
+            return false;
+        }
+
+        let mut sign = N::zero();
+        let mut total = N::zero();
+        for i in 0..n {
+            let (a, b, c) = (self.vertices[i], self.vertices[(i + 1) % n], self.vertices[(i + 2) % n]);
+            let incoming = Point2d::line_between_points(a, b);
+            let outgoing = Point2d::line_between_points(b, c);
+            let scale = incoming.magnitude_sqr().sqrt() * outgoing.magnitude_sqr().sqrt();
+            if scale == N::zero() {
+                // Repeated vertex.
+                continue;
+            }
+            // Positive when c is to the right of a->b, so a clockwise turn.
+            let turn = incoming.wedge(&c.to_bivector()).e012 / scale;
+            if turn.abs() > tolerance {
+                if sign != N::zero() && turn.signum() != sign {
+                    return false;
+                }
+                sign = turn.signum();
+            }
+            total = total + incoming.angle_to(&outgoing).get_radians();
+        }
+        sign != N::zero() && (total.abs() - N::TAU()).abs() <= N::PI()
+    }
+}
+
+// Containment //
+
+impl<N: Float> Polygon2d<N> {
+    /// How many times this polygon goes counterclockwise around a point.  Clockwise loops count as negative.
+    ///
+    /// Each edge that crosses the horizontal line through the point counts when the point is on the correct side of
+    /// it.  That's the orientation of the edge's ends with the point, which is exact, see `predicates::orient2d`.
+    /// The edge's line isn't built first, since rounding the join could put a point very near the edge on the wrong
+    /// side.
+    pub fn winding_number(&self, point: &Point2d<N>) -> i32 {
+        self.edges()
+            .map(|edge| {
+                let turn = orient2d(&edge.start, &edge.end, point);
+                if edge.start.y <= point.y {
+                    // Upwards, with the point on the left.
+                    if edge.end.y > point.y && turn == Orientation::CounterClockwise { 1 } else { 0 }
+                } else if edge.end.y <= point.y && turn == Orientation::Clockwise {
+                    // Downwards, with the point on the right.
+                    -1
+                } else {
+                    0
+                }
+            })
+            .sum()
+    }
+
+    /// Whether a point is inside this polygon, by the provided fill rule.
+    pub fn contains(&self, point: &Point2d<N>, rule: FillRule) -> bool {
+        let winding = self.winding_number(point);
+        match rule {
+            FillRule::EvenOdd => winding % 2 != 0,
+            FillRule::NonZero => winding != 0,
+        }
+    }
+}
+
+/// Anything which can transform a bivector can transform a polygon.
+impl<N: Float, T: RigidTransformation<Bivector<N>>> RigidTransformation<Polygon2d<N>> for T {
+    fn apply(&self, target: &Polygon2d<N>) -> Polygon2d<N> {
+        Polygon2d {
+            vertices: target.vertices
+                .iter()
+                .map(|vertex| Point2d::from_bivector(&self.apply(&vertex.to_bivector())))
+                .collect(),
+        }
+    }
+}
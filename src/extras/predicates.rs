@@ -0,0 +1,342 @@
+use core::cmp::Ordering;
+
+use num_traits::Float;
+
+use crate::defs::{vector::Vector, bivector::Bivector};
+
+use super::point2d::Point2d;
+
+// ROBUST PREDICATES //
+// Exact signs for the products that geometry code branches on, in the style of Shewchuk's adaptive predicates.
+// Each predicate first computes its determinant the normal way, along with a bound on the rounding error.  Only when
+// the result is too close to zero to trust does it redo the computation exactly, as an expansion: a sum of floats
+// which don't overlap, so the sign of the largest one is the sign of the total.
+// Overflow and underflow aren't accounted for.
+
+/// Which way three points turn.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Orientation {
+    CounterClockwise,
+    Clockwise,
+    Collinear,
+}
+
+/// Which way `p`, `q`, `r` turn, exactly.
+///
+/// In PGA, this is the sign of the trivector `(p ∨ q) ∧ r`: the join of the first two points, met with the third.
+/// That's negative when `r` is to the left of the line from `p` to `q`, which is a counterclockwise turn.
+pub fn orient2d<N: Float>(p: &Point2d<N>, q: &Point2d<N>, r: &Point2d<N>) -> Orientation {
+    // The determinant is (px - rx)(qy - ry) - (py - ry)(qx - rx), positive when counterclockwise.
+    let left = (p.x - r.x) * (q.y - r.y);
+    let right = (p.y - r.y) * (q.x - r.x);
+    let det = left - right;
+    let bound = (three::<N>() + sixteen::<N>() * epsilon::<N>()) * epsilon::<N>() * (left.abs() + right.abs());
+
+    let sign = if det.abs() > bound {
+        sign_of(det)
+    } else {
+        // Multiplied out, so every product is of two inputs and can be made exact.
+        let mut exact = Expansion::<N, 12>::new();
+        exact.add_product(p.x, q.y);
+        exact.add_product(-p.x, r.y);
+        exact.add_product(-p.y, q.x);
+        exact.add_product(p.y, r.x);
+        exact.add_product(q.x, r.y);
+        exact.add_product(-q.y, r.x);
+        exact.sign()
+    };
+
+    match sign {
+        Ordering::Greater => Orientation::CounterClockwise,
+        Ordering::Less => Orientation::Clockwise,
+        Ordering::Equal => Orientation::Collinear,
+    }
+}
+
+/// Where `d` is compared to the circle through `a`, `b` and `c`, exactly.  `Greater` is inside, `Less` is outside,
+/// and `Equal` is on the circle.  If `a`, `b`, `c` turn clockwise, inside and outside swap.
+///
+/// This is `orient2d` one dimension up: each point is lifted onto the paraboloid z = x² + y², and `d` is inside the
+/// circle when its lift is below the plane through the other three.
+pub fn incircle<N: Float>(a: &Point2d<N>, b: &Point2d<N>, c: &Point2d<N>, d: &Point2d<N>) -> Ordering {
+    let (adx, ady) = (a.x - d.x, a.y - d.y);
+    let (bdx, bdy) = (b.x - d.x, b.y - d.y);
+    let (cdx, cdy) = (c.x - d.x, c.y - d.y);
+    let a_lift = adx * adx + ady * ady;
+    let b_lift = bdx * bdx + bdy * bdy;
+    let c_lift = cdx * cdx + cdy * cdy;
+    let det = a_lift * (bdx * cdy - cdx * bdy)
+        + b_lift * (cdx * ady - adx * cdy)
+        + c_lift * (adx * bdy - bdx * ady);
+    let permanent = a_lift * ((bdx * cdy).abs() + (cdx * bdy).abs())
+        + b_lift * ((cdx * ady).abs() + (adx * cdy).abs())
+        + c_lift * ((adx * bdy).abs() + (bdx * ady).abs());
+    // Shewchuk's bound for this form, which accounts for the differences being rounded too.
+    let bound = (ten::<N>() + ninety_six::<N>() * epsilon::<N>()) * epsilon::<N>() * permanent;
+    if det.abs() > bound {
+        return sign_of(det);
+    }
+
+    // Usually the differences were exact (nearby floats subtract exactly), and the determinant above only needs
+    // its products made exact.
+    let differences = [(a.x, d.x), (a.y, d.y), (b.x, d.x), (b.y, d.y), (c.x, d.x), (c.y, d.y)];
+    if differences.iter().all(|&(p, q)| two_sum(p, -q).1 == N::zero()) {
+        let mut exact = Expansion::<N, 192>::new();
+        let rows = [(adx, ady, bdx, bdy, cdx, cdy), (bdx, bdy, cdx, cdy, adx, ady), (cdx, cdy, adx, ady, bdx, bdy)];
+        for (px, py, qx, qy, rx, ry) in rows {
+            // (px² + py²)(qx ry - rx qy)
+            for lift in [px, py] {
+                exact.add_product4(lift, lift, qx, ry);
+                exact.add_product4(-lift, lift, rx, qy);
+            }
+        }
+        return exact.sign();
+    }
+
+    // Otherwise, multiplied out from the 4x4 determinant of the rows (x, y, x² + y², 1), so every term is a product
+    // of four inputs.  Each term picks which row gives the x, y and lifted columns, and the last row gives the 1.
+    let points = [a, b, c, d];
+    let mut exact = Expansion::<N, 768>::new();
+    for i in 0..4 {
+        for j in (0..4).filter(|&j| j != i) {
+            for k in (0..4).filter(|&k| k != i && k != j) {
+                let l = 6 - i - j - k;
+                let sign = if permutation_is_even([i, j, k, l]) { N::one() } else { -N::one() };
+                let (x, y, lifted) = (points[i].x * sign, points[j].y, points[k]);
+                exact.add_product4(x, y, lifted.x, lifted.x);
+                exact.add_product4(x, y, lifted.y, lifted.y);
+            }
+        }
+    }
+    exact.sign()
+}
+
+/// The exact sign of the meet of a line with a point (the trivector `l ∧ P`), compared to zero.
+/// `Greater` is the side the line's normal points to.  Neither needs to be normalized, but a point with a negative
+/// weight (e12) flips the sign.
+pub fn side_of_line<N: Float>(line: &Vector<N>, point: &Bivector<N>) -> Ordering {
+    let terms = [line.e1 * point.e20, line.e2 * point.e01, line.e0 * point.e12];
+    let det = terms[0] + terms[1] + terms[2];
+    let magnitude = terms[0].abs() + terms[1].abs() + terms[2].abs();
+    // Three products and two sums, each off by at most epsilon.  Doubled to stay well clear of second order terms.
+    let bound = sixteen::<N>() * epsilon::<N>() * magnitude;
+    if det.abs() > bound {
+        return sign_of(det);
+    }
+
+    let mut exact = Expansion::<N, 6>::new();
+    exact.add_product(line.e1, point.e20);
+    exact.add_product(line.e2, point.e01);
+    exact.add_product(line.e0, point.e12);
+    exact.sign()
+}
+
+/// Whether a point is exactly on a line.  See `side_of_line`.
+pub fn is_on_line<N: Float>(line: &Vector<N>, point: &Bivector<N>) -> bool {
+    side_of_line(line, point) == Ordering::Equal
+}
+
+/// Whether three lines meet at a single point, exactly.  This is when the trivector `l1 ∧ l2 ∧ l3` is zero.
+/// Parallel lines meet at a point at infinity, so three parallel lines are concurrent, as are any lines that
+/// coincide.
+pub fn are_concurrent<N: Float>(l1: &Vector<N>, l2: &Vector<N>, l3: &Vector<N>) -> bool {
+    orient_lines(l1, l2, l3) == Ordering::Equal
+}
+
+/// The exact sign of the trivector `l1 ∧ l2 ∧ l3`, compared to zero.  This is `side_of_line(l3, &l1.wedge(l2))`,
+/// without rounding the meet, so it's which side of the third line the first two meet on, times the weight of
+/// their meet.
+pub fn orient_lines<N: Float>(l1: &Vector<N>, l2: &Vector<N>, l3: &Vector<N>) -> Ordering {
+    // The determinant of the coefficients, as its six terms.
+    let terms = [
+        (l1.e0, l2.e1, l3.e2),
+        (l1.e1, l2.e2, l3.e0),
+        (l1.e2, l2.e0, l3.e1),
+        (-l1.e0, l2.e2, l3.e1),
+        (-l1.e1, l2.e0, l3.e2),
+        (-l1.e2, l2.e1, l3.e0),
+    ];
+    let mut det = N::zero();
+    let mut magnitude = N::zero();
+    for (a, b, c) in terms {
+        det = det + a * b * c;
+        magnitude = magnitude + (a * b * c).abs();
+    }
+    // Two roundings per term and five sums.  Doubled, as above.
+    let bound = sixteen::<N>() * epsilon::<N>() * magnitude;
+    if det.abs() > bound {
+        return sign_of(det);
+    }
+
+    let mut exact = Expansion::<N, 24>::new();
+    for (a, b, c) in terms {
+        // a * (bc), with bc split into its exact high and low parts.
+        let (high, low) = two_product(b, c);
+        exact.add_product(a, high);
+        exact.add_product(a, low);
+    }
+    exact.sign()
+}
+
+/// Which way the direction of `l2` turns from the direction of `l1`, exactly.  `Collinear` when they're parallel,
+/// whether they point the same way or not.
+///
+/// This is the sign of the weight (e12) of the meet `l1 ∧ l2`, which is zero for parallel lines, since they meet
+/// at infinity.
+pub fn turn_between<N: Float>(l1: &Vector<N>, l2: &Vector<N>) -> Orientation {
+    let left = l1.e1 * l2.e2;
+    let right = l1.e2 * l2.e1;
+    let det = left - right;
+    let bound = sixteen::<N>() * epsilon::<N>() * (left.abs() + right.abs());
+
+    let sign = if det.abs() > bound {
+        sign_of(det)
+    } else {
+        let mut exact = Expansion::<N, 4>::new();
+        exact.add_product(l1.e1, l2.e2);
+        exact.add_product(-l1.e2, l2.e1);
+        exact.sign()
+    };
+
+    match sign {
+        Ordering::Greater => Orientation::CounterClockwise,
+        Ordering::Less => Orientation::Clockwise,
+        Ordering::Equal => Orientation::Collinear,
+    }
+}
+
+// Expansion arithmetic //
+
+/// A sum of nonoverlapping floats, smallest first, with up to `CAP` terms.
+struct Expansion<N: Float, const CAP: usize> {
+    terms: [N; CAP],
+    len: usize,
+}
+
+impl<N: Float, const CAP: usize> Expansion<N, CAP> {
+    fn new() -> Self {
+        Expansion { terms: [N::zero(); CAP], len: 0 }
+    }
+
+    /// Add a float to the expansion, exactly.  Shewchuk's GROW-EXPANSION, dropping zeros as it goes.
+    fn add(&mut self, value: N) {
+        if value == N::zero() {
+            // Exact products often have no error part, so this is worth skipping.
+            return;
+        }
+        let mut carry = value;
+        let mut len = 0;
+        for i in 0..self.len {
+            let (sum, error) = two_sum(carry, self.terms[i]);
+            if error != N::zero() {
+                self.terms[len] = error;
+                len += 1;
+            }
+            carry = sum;
+        }
+        if carry != N::zero() {
+            self.terms[len] = carry;
+            len += 1;
+        }
+        self.len = len;
+    }
+
+    /// Add the product of two floats, exactly.
+    fn add_product(&mut self, a: N, b: N) {
+        let (high, low) = two_product(a, b);
+        self.add(low);
+        self.add(high);
+    }
+
+    /// Add the product of four floats, exactly.  The first two make two terms, those times the third make four, and
+    /// those times the fourth make eight.
+    fn add_product4(&mut self, a: N, b: N, c: N, d: N) {
+        let (high, low) = two_product(a, b);
+        for ab in [high, low] {
+            let (high, low) = two_product(ab, c);
+            for abc in [high, low] {
+                self.add_product(abc, d);
+            }
+        }
+    }
+
+    /// The sign of the whole sum, which is the sign of the largest term.
+    fn sign(&self) -> Ordering {
+        match self.len {
+            0 => Ordering::Equal,
+            len => sign_of(self.terms[len - 1]),
+        }
+    }
+}
+
+/// `a + b` as a rounded sum and the exact rounding error.  Knuth's TWO-SUM.
+fn two_sum<N: Float>(a: N, b: N) -> (N, N) {
+    let sum = a + b;
+    let b_virtual = sum - a;
+    let a_virtual = sum - b_virtual;
+    let error = (a - a_virtual) + (b - b_virtual);
+    (sum, error)
+}
+
+/// `a * b` as a rounded product and the exact rounding error.  Dekker's TWO-PRODUCT, which splits each float into
+/// two halves whose products are all exact.  A fused multiply-add would give the error directly, but without
+/// hardware support that's a slow library call, and this runs on every exact fallback.
+fn two_product<N: Float>(a: N, b: N) -> (N, N) {
+    let product = a * b;
+    let (a_high, a_low) = split(a);
+    let (b_high, b_low) = split(b);
+    let error = a_low * b_low - (((product - a_high * b_high) - a_low * b_high) - a_high * b_low);
+    (product, error)
+}
+
+/// A float as two halves, each with at most half the bits of precision, which add up to it exactly.
+fn split<N: Float>(a: N) -> (N, N) {
+    let c = splitter::<N>() * a;
+    let high = c - (c - a);
+    (high, a - high)
+}
+
+/// 2^⌈p/2⌉ + 1, for p bits of precision.  That's 2^27 + 1 for `f64`.
+fn splitter<N: Float>() -> N {
+    let precision = N::one() - N::epsilon().log2();
+    let half = ((precision + N::one()) / N::from(2.0).unwrap()).floor();
+    N::from(2.0).unwrap().powf(half) + N::one()
+}
+
+fn sign_of<N: Float>(value: N) -> Ordering {
+    value.partial_cmp(&N::zero()).unwrap_or(Ordering::Equal)
+}
+
+/// Half of machine epsilon, which bounds the relative error of one rounding.
+fn epsilon<N: Float>() -> N {
+    N::epsilon() * N::from(0.5).unwrap()
+}
+
+/// Whether a permutation of 0..4 takes an even number of swaps.
+fn permutation_is_even(mut permutation: [usize; 4]) -> bool {
+    let mut even = true;
+    for i in 0..4 {
+        while permutation[i] != i {
+            let target = permutation[i];
+            permutation.swap(i, target);
+            even = !even;
+        }
+    }
+    even
+}
+
+fn three<N: Float>() -> N {
+    N::from(3.0).unwrap()
+}
+
+fn ten<N: Float>() -> N {
+    N::from(10.0).unwrap()
+}
+
+fn sixteen<N: Float>() -> N {
+    N::from(16.0).unwrap()
+}
+
+fn ninety_six<N: Float>() -> N {
+    N::from(96.0).unwrap()
+}
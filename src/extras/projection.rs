@@ -0,0 +1,73 @@
+use num_traits::Float;
+
+use crate::{defs::{vector::Vector, bivector::Bivector}, traits::{GeometricProduct, OuterProduct}};
+
+use super::point2d::Point2d;
+
+// PROJECTIONS AND PERPENDICULARS //
+// The inner product of a line and a point is the line through the point, perpendicular to the original line.
+// Every construction in here is built from that:
+//  * Wedging it with the original line gives the foot of the perpendicular, `(l·P)∧l`.
+//  * Multiplying it with the point gives the parallel line through the point, `(l·P)P`.
+// Note that results are NOT normalized.
+
+impl<N: Float> Vector<N> {
+    /// The inner product of this line and a point, which is the line through the point perpendicular to this one.
+    /// Its orientation is this line's, rotated a quarter turn counterclockwise.
+    pub fn perpendicular_through(&self, point: &Bivector<N>) -> Vector<N> {
+        self.geo(point)
+            .vector
+    }
+
+    /// Project a point onto this line.  This gives the foot of the perpendicular from the point to the line.
+    pub fn project_point(&self, point: &Bivector<N>) -> Bivector<N> {
+        self.wedge(&self.perpendicular_through(point))
+    }
+
+    /// Project this line onto a point.  This gives the line through the point which is parallel to this one,
+    /// with the same orientation.
+    pub fn project_onto(&self, point: &Bivector<N>) -> Vector<N> {
+        point.geo(&self.perpendicular_through(point))
+            .vector
+    }
+}
+
+impl<N: Float> Bivector<N> {
+    /// Project this point onto a line.  See `Vector::project_point`.
+    pub fn project_onto(&self, line: &Vector<N>) -> Bivector<N> {
+        line.project_point(self)
+    }
+
+    /// The line through this point, perpendicular to the provided line.  See `Vector::perpendicular_through`.
+    pub fn perpendicular_to(&self, line: &Vector<N>) -> Vector<N> {
+        line.perpendicular_through(self)
+    }
+
+    /// Reject this point from a line.  This is the point at infinity (direction) from the foot of the perpendicular
+    /// to this point, with a length equal to the distance between them.
+    pub fn reject_from(&self, line: &Vector<N>) -> Bivector<N> {
+        let foot = self.project_onto(line);
+        Bivector {
+            e01: self.e01 / self.e12 - foot.e01 / foot.e12,
+            e20: self.e20 / self.e12 - foot.e20 / foot.e12,
+            e12: N::zero(),
+        }
+    }
+}
+
+impl<N: Float> Point2d<N> {
+    /// The closest point on a line to this point.  That is, the foot of the perpendicular.
+    pub fn project_onto(&self, line: &Vector<N>) -> Point2d<N> {
+        Point2d::from_bivector(&line.project_point(&self.to_bivector()))
+    }
+
+    /// The line through this point, perpendicular to the provided line.
+    pub fn perpendicular_to(&self, line: &Vector<N>) -> Vector<N> {
+        line.perpendicular_through(&self.to_bivector())
+    }
+
+    /// The line through this point, parallel to the provided line.
+    pub fn parallel_to(&self, line: &Vector<N>) -> Vector<N> {
+        line.project_onto(&self.to_bivector())
+    }
+}
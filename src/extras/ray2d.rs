@@ -0,0 +1,113 @@
+use num_traits::Float;
+
+use crate::defs::bivector::Bivector;
+
+use super::{
+    direction2d::Direction2d,
+    line2d::{Line2d, default_tolerance},
+    point2d::Point2d,
+    segment2d::{Crossing, Segment2d, crossing_parameter},
+    transformations::RigidTransformation,
+};
+
+/// A ray, starting at a point and going forever in a direction.  Points along it are `origin + direction * t` for
+/// `t >= 0`, so `t` is measured in multiples of the direction's length.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ray2d<N: Float> {
+    pub origin: Point2d<N>,
+    pub direction: Direction2d<N>,
+}
+
+// Constructors and conversions for ray 2d //
+
+impl<N: Float> Ray2d<N> {
+    /// Create a new ray.
+    /// This is a `const fn`, so it can be used to build `const` and `static` rays.
+    pub const fn new(origin: Point2d<N>, direction: Direction2d<N>) -> Ray2d<N> {
+        Ray2d { origin, direction }
+    }
+
+    /// A ray from `origin` which passes through `target` at `t = 1`.
+    pub fn through(origin: Point2d<N>, target: Point2d<N>) -> Ray2d<N> {
+        Ray2d { origin, direction: target - origin }
+    }
+
+    /// The line this ray lies on, pointing the same way.  This is the join of the origin with the direction's point
+    /// at infinity.
+    pub fn line(&self) -> Line2d<N> {
+        Line2d::from_point_direction(self.origin, self.direction)
+    }
+
+    /// The point at parameter `t`.
+    pub fn point_at(&self, t: N) -> Point2d<N> {
+        self.origin + self.direction * t
+    }
+}
+
+// Intersections //
+
+impl<N: Float> Ray2d<N> {
+    /// Intersect this ray with a line, with the default tolerance.  See `intersect_line_with_tolerance`.
+    pub fn intersect_line(&self, line: &Line2d<N>) -> Option<Crossing<N>> {
+        self.intersect_line_with_tolerance(line, default_tolerance())
+    }
+
+    /// Intersect this ray with a line.  `t` is the parameter along the ray, and `u` is the signed distance along the
+    /// line's direction.  A ray parallel to the line (within `tolerance`, as a sine) misses it.
+    pub fn intersect_line_with_tolerance(&self, line: &Line2d<N>, tolerance: N) -> Option<Crossing<N>> {
+        let t = crossing_parameter(&line.to_vector(), &self.origin, self.direction, tolerance)?;
+        if t < N::zero() {
+            return None;
+        }
+        let point = self.point_at(t);
+        let direction = line.direction();
+        Some(Crossing { point, t, u: point.x * direction.x + point.y * direction.y })
+    }
+
+    /// Intersect this ray with a segment, with the default tolerance.  See `intersect_segment_with_tolerance`.
+    pub fn intersect_segment(&self, segment: &Segment2d<N>) -> Option<Crossing<N>> {
+        self.intersect_segment_with_tolerance(segment, default_tolerance())
+    }
+
+    /// Intersect this ray with a segment.  `t` is the parameter along the ray, and `u` along the segment.
+    /// If the ray runs along the segment, this gives the first point of the segment it reaches.
+    pub fn intersect_segment_with_tolerance(&self, segment: &Segment2d<N>, tolerance: N) -> Option<Crossing<N>> {
+        match crossing_parameter(&segment.to_vector(), &self.origin, self.direction, tolerance) {
+            Some(t) => {
+                let u = crossing_parameter(&self.line().to_vector(), &segment.start, segment.direction(), tolerance)?;
+                if t < N::zero() || u < N::zero() || u > N::one() {
+                    return None;
+                }
+                Some(Crossing { point: self.point_at(t), t, u })
+            },
+            None => {
+                if self.line().to_vector().distance_to_point(&segment.start.to_bivector()).abs() > tolerance {
+                    return None;
+                }
+                let length_sqr = self.direction.dot(&self.direction);
+                if length_sqr == N::zero() {
+                    return None;
+                }
+                // Collinear, so the first point reached is whichever end is nearest, unless the ray starts inside.
+                let a = (segment.start - self.origin).dot(&self.direction) / length_sqr;
+                let b = (segment.end - self.origin).dot(&self.direction) / length_sqr;
+                if a.max(b) < N::zero() {
+                    return None;
+                }
+                let t = a.min(b).max(N::zero());
+                let point = self.point_at(t);
+                Some(Crossing { point, t, u: segment.closest_parameter(&point) })
+            },
+        }
+    }
+}
+
+/// Anything which can transform a bivector can transform a ray.
+impl<N: Float, T: RigidTransformation<Bivector<N>>> RigidTransformation<Ray2d<N>> for T {
+    fn apply(&self, target: &Ray2d<N>) -> Ray2d<N> {
+        Ray2d {
+            origin: Point2d::from_bivector(&self.apply(&target.origin.to_bivector())),
+            direction: Direction2d::from_bivector(&self.apply(&target.direction.to_bivector())),
+        }
+    }
+}
@@ -0,0 +1,233 @@
+use num_traits::Float;
+
+use crate::defs::vector::Vector;
+
+use super::{
+    capsule2d::Capsule2d,
+    circle2d::{Circle2d, CircleIntersection},
+    direction2d::Direction2d,
+    line2d::Line2d,
+    point2d::Point2d,
+    polygon2d::{FillRule, Polygon2d},
+    ray2d::Ray2d,
+    segment2d::Segment2d,
+    transformations::{Motor, MultiTransform, RigidTransformation, Transformer},
+};
+
+// RAY CASTING //
+// Each kind of shape finds the first point a ray reaches on its boundary, with the intersections on `Ray2d` and
+// `Circle2d`.  A capsule is its two sides and the circles on its ends, and a polygon is its edges.  A ray which
+// starts inside a shape hits it straight away, so picking with the mouse finds whatever's under the pointer.
+// Shapes stored in their own coordinates move the ray into those coordinates with the inverse of their placement,
+// and move the hit back out, so nothing has to be transformed but the ray.
+// Requires the `alloc` feature.
+
+/// Where a ray hits a shape.  Requires the `alloc` feature.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Hit<N: Float> {
+    /// How far along the ray, in the same units as the shapes, no matter how long the ray's direction is.
+    pub distance: N,
+    pub point: Point2d<N>,
+    /// The line through the point along the surface's normal, normalized, and pointing out of the surface towards
+    /// the side the ray came from.  A ray which starts inside a shape gets a normal pointing back along it.
+    pub normal: Vector<N>,
+    /// The index of the shape that was hit, in the slice passed to `raycast`.  Shapes on their own give 0.
+    pub shape: usize,
+}
+
+/// Anything a ray can hit.  Requires the `alloc` feature.
+pub trait Raycast<N: Float> {
+    /// The first point where `ray` reaches this shape, if it does.  The ray's direction can't be zero.
+    fn raycast(&self, ray: &Ray2d<N>) -> Option<Hit<N>>;
+}
+
+/// One of the shapes rays can hit, so different kinds can be kept in one slice.  Requires the `alloc` feature.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Shape<N: Float> {
+    Segment(Segment2d<N>),
+    /// Any polygon, which can be concave, and go either way around.
+    Polygon(Polygon2d<N>),
+    Circle(Circle2d<N>),
+    Capsule(Capsule2d<N>),
+}
+
+/// A shape stored in its own coordinates, and placed in the world by a rigid transformation.  Requires the `alloc`
+/// feature.
+///
+/// Rays are moved into the shape's coordinates by the inverse of the placement, and hits are moved back out by the
+/// placement itself.  Moving the shape only means making a new placement.
+pub struct Local<N: Float, S: Raycast<N>> {
+    shape: S,
+    to_world: MultiTransform<N>,
+    to_local: MultiTransform<N>,
+}
+
+// Ray casts //
+
+/// Cast a ray at some shapes, and find the first one it hits.  If several are hit at the same distance, the first of
+/// them in the slice wins.
+pub fn raycast<N: Float, S: Raycast<N>>(ray: &Ray2d<N>, shapes: &[S]) -> Option<Hit<N>> {
+    first(shapes.iter().enumerate().filter_map(|(shape, candidate)| candidate.raycast(ray).map(|hit| Hit { shape, ..hit })))
+}
+
+// Constructors and accessors for local shapes //
+
+impl<N: Float, S: Raycast<N>> Local<N, S> {
+    /// Place `shape` by translating it with `motor`.  Rays are moved back with `motor.inverse()`.
+    pub fn new(shape: S, motor: &Motor<N>) -> Local<N, S> {
+        Local {
+            shape,
+            to_world: MultiTransform::from_slice(&[motor.get_transformer()]),
+            to_local: MultiTransform::from_slice(&[motor.inverse().get_transformer()]),
+        }
+    }
+
+    /// Place `shape` with some transformers, applied in order, such as a rotor and then a motor.
+    pub fn from_slice(shape: S, transformations: &[&Transformer<N>]) -> Local<N, S> {
+        let to_world = MultiTransform::from_slice(transformations);
+        let to_local = to_world.inverse();
+        Local { shape, to_world, to_local }
+    }
+
+    /// The shape, in its own coordinates.
+    pub fn shape(&self) -> &S {
+        &self.shape
+    }
+}
+
+// Conversions to shapes //
+
+impl<N: Float> From<Segment2d<N>> for Shape<N> {
+    fn from(segment: Segment2d<N>) -> Shape<N> {
+        Shape::Segment(segment)
+    }
+}
+
+impl<N: Float> From<Polygon2d<N>> for Shape<N> {
+    fn from(polygon: Polygon2d<N>) -> Shape<N> {
+        Shape::Polygon(polygon)
+    }
+}
+
+impl<N: Float> From<Circle2d<N>> for Shape<N> {
+    fn from(circle: Circle2d<N>) -> Shape<N> {
+        Shape::Circle(circle)
+    }
+}
+
+impl<N: Float> From<Capsule2d<N>> for Shape<N> {
+    fn from(capsule: Capsule2d<N>) -> Shape<N> {
+        Shape::Capsule(capsule)
+    }
+}
+
+// Ray casts against each shape //
+
+/// The segment's normal is whichever side faces the ray.  A ray running along the segment hits its nearest end.
+impl<N: Float> Raycast<N> for Segment2d<N> {
+    fn raycast(&self, ray: &Ray2d<N>) -> Option<Hit<N>> {
+        let crossing = ray.intersect_segment(self)?;
+        let outward = self.direction().perpendicular();
+        let outward = match outward.dot(&ray.direction) {
+            along if along > N::zero() => -outward,
+            along if along < N::zero() => outward,
+            _ => -ray.direction,
+        };
+        Some(hit(ray, crossing.t, crossing.point, outward))
+    }
+}
+
+/// A polygon is hit on its edges, or straight away if the ray starts inside it.
+impl<N: Float> Raycast<N> for Polygon2d<N> {
+    fn raycast(&self, ray: &Ray2d<N>) -> Option<Hit<N>> {
+        if self.contains(&ray.origin, FillRule::NonZero) {
+            return Some(hit(ray, N::zero(), ray.origin, -ray.direction));
+        }
+        first(self.edges().filter_map(|edge| edge.raycast(ray)))
+    }
+}
+
+impl<N: Float> Raycast<N> for Circle2d<N> {
+    fn raycast(&self, ray: &Ray2d<N>) -> Option<Hit<N>> {
+        if self.contains(&ray.origin) {
+            return Some(hit(ray, N::zero(), ray.origin, -ray.direction));
+        }
+        // The crossings are in order along the line, which points the same way as the ray.
+        let point = match self.intersect_line(&ray.line()) {
+            CircleIntersection::One(point) | CircleIntersection::Two(point, _) => point,
+            _ => return None,
+        };
+        let t = (point - ray.origin).dot(&ray.direction) / ray.direction.dot(&ray.direction);
+        if t < N::zero() {
+            return None;
+        }
+        Some(hit(ray, t, point, point - self.center))
+    }
+}
+
+/// A capsule is hit on its sides, or the circles on its ends.  The first of those the ray reaches is always on the
+/// outside.
+impl<N: Float> Raycast<N> for Capsule2d<N> {
+    fn raycast(&self, ray: &Ray2d<N>) -> Option<Hit<N>> {
+        let Capsule2d { segment, radius } = *self;
+        if self.contains(&ray.origin) {
+            return Some(hit(ray, N::zero(), ray.origin, -ray.direction));
+        }
+        let (start, end) = (Circle2d::new(segment.start, radius), Circle2d::new(segment.end, radius));
+        if segment.start == segment.end {
+            return start.raycast(ray);
+        }
+        let offset = segment.direction().perpendicular().normalized() * radius;
+        let sides = [
+            Segment2d::new(segment.start + offset, segment.end + offset),
+            Segment2d::new(segment.start - offset, segment.end - offset),
+        ];
+        first(sides.iter().filter_map(|side| side.raycast(ray)).chain([start.raycast(ray), end.raycast(ray)].into_iter().flatten()))
+    }
+}
+
+impl<N: Float> Raycast<N> for Shape<N> {
+    fn raycast(&self, ray: &Ray2d<N>) -> Option<Hit<N>> {
+        match self {
+            Shape::Segment(segment) => segment.raycast(ray),
+            Shape::Polygon(polygon) => polygon.raycast(ray),
+            Shape::Circle(circle) => circle.raycast(ray),
+            Shape::Capsule(capsule) => capsule.raycast(ray),
+        }
+    }
+}
+
+impl<N: Float, S: Raycast<N>> Raycast<N> for Local<N, S> {
+    fn raycast(&self, ray: &Ray2d<N>) -> Option<Hit<N>> {
+        let hit = self.shape.raycast(&self.to_local.apply(ray))?;
+        Some(Hit {
+            point: Point2d::from_bivector(&self.to_world.apply(&hit.point.to_bivector())),
+            normal: self.to_world.apply(&hit.normal),
+            ..hit
+        })
+    }
+}
+
+/// References to shapes can be cast at too, so a slice of `&dyn Raycast` can mix any kinds of shapes.
+impl<N: Float, S: Raycast<N> + ?Sized> Raycast<N> for &S {
+    fn raycast(&self, ray: &Ray2d<N>) -> Option<Hit<N>> {
+        (**self).raycast(ray)
+    }
+}
+
+// Helpers //
+
+/// A hit at parameter `t` along the ray, with its normal pointing along `outward`.
+fn hit<N: Float>(ray: &Ray2d<N>, t: N, point: Point2d<N>, outward: Direction2d<N>) -> Hit<N> {
+    Hit {
+        distance: t * ray.direction.length(),
+        point,
+        normal: Line2d::from_point_direction(point, outward).to_vector(),
+        shape: 0,
+    }
+}
+
+/// The nearest of some hits, or the first of the nearest.
+fn first<N: Float>(hits: impl Iterator<Item = Hit<N>>) -> Option<Hit<N>> {
+    hits.min_by(|a, b| a.distance.partial_cmp(&b.distance).unwrap_or(core::cmp::Ordering::Equal))
+}
@@ -0,0 +1,204 @@
+use alloc::vec::Vec;
+
+use num_traits::Float;
+
+use crate::{defs::{vector::Vector, bivector::Bivector}, traits::{Contraction, OuterProduct, RegressiveProduct, Normalize}};
+
+use super::{line2d::default_tolerance, point2d::Point2d, polygon2d::{Polygon2d, Winding}, transformations::RigidTransformation};
+
+// SEPARATING AXES //
+// Two convex polygons are apart exactly when one of their edge lines has all of the other polygon in front of it.
+// With normalized lines and points, `l ∧ P` is the signed distance from the line to the point, so each edge's
+// separation is the smallest of those over the other polygon's vertices, and the edge with the largest separation
+// is the best axis.  If that's negative, the polygons overlap, and it's the edge the other polygon sinks into least.
+// That edge is the reference edge.  The incident edge is the edge of the other polygon facing it most directly: the
+// one whose line has the most negative inner product with it.  The incident edge is clipped to the strip beside the
+// reference edge, between the lines through its ends perpendicular to it, and whatever's left behind the reference
+// edge are the contacts.  That gives two contacts for a box resting on a box, which is what keeps stacks steady.
+// Requires the `alloc` feature.
+
+/// A convex polygon, as its vertices and the lines through its edges, ready for `collide`.  Requires the `alloc`
+/// feature.
+///
+/// The vertices are normalized points, counterclockwise, and edge `i` is the normalized line from vertex `i` to the
+/// next, so its normal points outwards.  Transforming one moves the points and lines directly, with no conversions.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConvexPolygon<N: Float> {
+    vertices: Vec<Bivector<N>>,
+    edges: Vec<Vector<N>>,
+}
+
+/// A point where two polygons touch.  Requires the `alloc` feature.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContactPoint<N: Float> {
+    /// A point of the incident edge, behind the reference edge, normalized.
+    pub point: Bivector<N>,
+    /// How far the point is behind the reference edge.
+    pub depth: N,
+}
+
+/// Where two polygons overlap.  Requires the `alloc` feature.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Manifold<N: Float> {
+    /// The line through the reference edge, normalized, and oriented so its normal points from the first polygon
+    /// towards the second.  Moving the second polygon `depth` along the normal separates them.
+    pub normal: Vector<N>,
+    /// The deepest of the contacts' depths.
+    pub depth: N,
+    /// One or two contacts.
+    pub contacts: Vec<ContactPoint<N>>,
+    /// The index of the reference edge, in the polygon it belongs to.
+    pub reference_edge: usize,
+    /// The index of the incident edge, in the other polygon.
+    pub incident_edge: usize,
+    /// Whether the reference edge belongs to the second polygon.
+    pub flipped: bool,
+}
+
+// Constructors and accessors for convex polygons //
+
+impl<N: Float> ConvexPolygon<N> {
+    /// Build from a convex polygon, which can go either way around.  Repeated vertices are dropped.
+    ///
+    /// Two vertices make a segment, whose edges are the same line, both ways.  With fewer than that, there are no
+    /// edges, and it never collides with anything.
+    pub fn new(polygon: &Polygon2d<N>) -> ConvexPolygon<N> {
+        let mut points: Vec<Point2d<N>> = match polygon.winding() {
+            Winding::Clockwise => polygon.reversed().into_vertices(),
+            _ => polygon.vertices().to_vec(),
+        };
+        points.dedup();
+        while points.len() > 1 && points.first() == points.last() {
+            points.pop();
+        }
+        let edges = match points.len() {
+            0 | 1 => Vec::new(),
+            n => (0..n).map(|i| Point2d::line_between_points(points[i], points[(i + 1) % n]).normalized()).collect(),
+        };
+        ConvexPolygon { vertices: points.iter().map(|point| point.to_bivector()).collect(), edges }
+    }
+
+    /// The vertices, counterclockwise, as normalized points.
+    pub fn vertices(&self) -> &[Bivector<N>] {
+        &self.vertices
+    }
+
+    /// The lines through the edges, normalized, with their normals pointing outwards.
+    pub fn edges(&self) -> &[Vector<N>] {
+        &self.edges
+    }
+
+    /// Back to a polygon, counterclockwise.
+    pub fn to_polygon(&self) -> Polygon2d<N> {
+        Polygon2d::new(self.vertices.iter().map(Point2d::from_bivector).collect())
+    }
+}
+
+// Collisions //
+
+/// Collide two convex polygons, with the default tolerance.  See `collide_with_tolerance`.
+pub fn collide<N: Float>(a: &ConvexPolygon<N>, b: &ConvexPolygon<N>) -> Option<Manifold<N>> {
+    collide_with_tolerance(a, b, default_tolerance())
+}
+
+/// Collide two convex polygons.  `None` if they're apart, or only touch.
+///
+/// The second polygon's best edge is only used as the reference edge if it's better than the first's by more than
+/// `tolerance`.  Otherwise, nearly equal edges (like the faces of two boxes resting on each other) would swap from
+/// step to step.
+pub fn collide_with_tolerance<N: Float>(a: &ConvexPolygon<N>, b: &ConvexPolygon<N>, tolerance: N) -> Option<Manifold<N>> {
+    let (edge_a, separation_a) = best_axis(a, b)?;
+    if separation_a >= N::zero() {
+        return None;
+    }
+    let (edge_b, separation_b) = best_axis(b, a)?;
+    if separation_b >= N::zero() {
+        return None;
+    }
+
+    let flipped = separation_b > separation_a + tolerance;
+    let (reference, incident, reference_edge) = if flipped { (b, a, edge_b) } else { (a, b, edge_a) };
+    let line = &reference.edges[reference_edge];
+
+    // The incident edge faces the reference edge most directly.
+    let incident_edge = (0..incident.edges.len())
+        .min_by(|&i, &j| line.inner(&incident.edges[i]).partial_cmp(&line.inner(&incident.edges[j])).unwrap_or(core::cmp::Ordering::Equal))?;
+    let n = incident.vertices.len();
+    let mut points = [incident.vertices[incident_edge].clone(), incident.vertices[(incident_edge + 1) % n].clone()];
+
+    // Clip to the strip beside the reference edge.  Each side keeps the points to its left.
+    let n = reference.vertices.len();
+    let (start, end) = (&reference.vertices[reference_edge], &reference.vertices[(reference_edge + 1) % n]);
+    for side in [line.perpendicular_through(start) * -N::one(), line.perpendicular_through(end)] {
+        points = clip(&points, &side)?;
+    }
+
+    let contacts: Vec<ContactPoint<N>> = points.into_iter()
+        .map(|point| ContactPoint { depth: -line.wedge(&point).e012, point })
+        .filter(|contact| contact.depth > N::zero())
+        .collect();
+    if contacts.is_empty() {
+        return None;
+    }
+    let depth = contacts.iter().map(|contact| contact.depth).fold(N::zero(), N::max);
+
+    let normal = if flipped { line.clone() * -N::one() } else { line.clone() };
+    Some(Manifold { normal, depth, contacts, reference_edge, incident_edge, flipped })
+}
+
+// Helpers //
+
+/// The edge of `polygon` with the most of `other` in front of it, and how far in front.  Negative if `other` reaches
+/// behind every edge.
+fn best_axis<N: Float>(polygon: &ConvexPolygon<N>, other: &ConvexPolygon<N>) -> Option<(usize, N)> {
+    let mut best: Option<(usize, N)> = None;
+    for (i, edge) in polygon.edges.iter().enumerate() {
+        let separation = other.vertices.iter()
+            .map(|vertex| edge.wedge(vertex).e012)
+            .fold(N::infinity(), N::min);
+        if best.is_none_or(|(_, most)| separation > most) {
+            best = Some((i, separation));
+        }
+    }
+    best
+}
+
+/// Clip the segment between two points to the half-plane left of `line`, where `l ∧ P <= 0`.  A point which is
+/// clipped off is replaced by the meet of the line with the segment's line.  `None` if both points are outside.
+fn clip<N: Float>(points: &[Bivector<N>; 2], line: &Vector<N>) -> Option<[Bivector<N>; 2]> {
+    let sides = [line.wedge(&points[0]).e012, line.wedge(&points[1]).e012];
+    match (sides[0] <= N::zero(), sides[1] <= N::zero()) {
+        (true, true) => Some(points.clone()),
+        (false, false) => None,
+        (inside_first, _) => {
+            let meet = line.wedge(&points[0].regressive(&points[1]));
+            let crossing = meet.clone() * (N::one() / meet.e12);
+            if inside_first {
+                Some([points[0].clone(), crossing])
+            } else {
+                Some([crossing, points[1].clone()])
+            }
+        },
+    }
+}
+
+/// Anything which can transform lines and points can transform a convex polygon.  The results are normalized again,
+/// so rounding doesn't build up over many steps.
+impl<N: Float, T: RigidTransformation<Bivector<N>> + RigidTransformation<Vector<N>>> RigidTransformation<ConvexPolygon<N>> for T {
+    fn apply(&self, target: &ConvexPolygon<N>) -> ConvexPolygon<N> {
+        ConvexPolygon {
+            vertices: target.vertices.iter()
+                .map(|vertex| {
+                    let moved: Bivector<N> = self.apply(vertex);
+                    moved.clone() * (N::one() / moved.e12)
+                })
+                .collect(),
+            edges: target.edges.iter()
+                .map(|edge| {
+                    let moved: Vector<N> = self.apply(edge);
+                    moved.normalized()
+                })
+                .collect(),
+        }
+    }
+}
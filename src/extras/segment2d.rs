@@ -0,0 +1,154 @@
+use num_traits::Float;
+
+use crate::{defs::{vector::Vector, bivector::Bivector}, traits::{OuterProduct, MagnitudeSqr}};
+
+use super::{direction2d::Direction2d, line2d::{Line2d, default_tolerance}, point2d::Point2d, transformations::RigidTransformation};
+
+/// A line segment between two points.  Points along it are `start + (end - start) * t` for `t` in `[0, 1]`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Segment2d<N: Float> {
+    pub start: Point2d<N>,
+    pub end: Point2d<N>,
+}
+
+/// Where two shapes cross.  `t` is the parameter along the first shape, and `u` is the parameter along the second.
+/// For segments and rays, these are the parameters used by `point_at`.  For a `Line2d`, `u` is the signed distance
+/// along its direction, measured from the closest point to the origin.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Crossing<N: Float> {
+    pub point: Point2d<N>,
+    pub t: N,
+    pub u: N,
+}
+
+// Constructors and conversions for segment 2d //
+
+impl<N: Float> Segment2d<N> {
+    /// Create a new segment between two points.
+    /// This is a `const fn`, so it can be used to build `const` and `static` segments.
+    pub const fn new(start: Point2d<N>, end: Point2d<N>) -> Segment2d<N> {
+        Segment2d { start, end }
+    }
+
+    /// The line through this segment, pointing from start to end.  This is the join of the two points, and is not
+    /// normalized; its norm is the length of the segment.
+    pub fn to_vector(&self) -> Vector<N> {
+        Point2d::line_between_points(self.start, self.end)
+    }
+
+    /// The line through this segment, pointing from start to end.
+    pub fn line(&self) -> Line2d<N> {
+        Line2d::from_vector(&self.to_vector())
+    }
+
+    /// The direction from start to end, with the length of the segment.
+    pub fn direction(&self) -> Direction2d<N> {
+        self.end - self.start
+    }
+
+    /// The length of this segment.  The norm of the join.
+    pub fn length(&self) -> N {
+        self.to_vector().magnitude_sqr().sqrt()
+    }
+
+    /// The point at parameter `t`.  0 is the start, and 1 is the end.
+    pub fn point_at(&self, t: N) -> Point2d<N> {
+        self.start + self.direction() * t
+    }
+}
+
+// Queries //
+
+impl<N: Float> Segment2d<N> {
+    /// The parameter of the closest point on this segment to `point`, in `[0, 1]`.
+    pub fn closest_parameter(&self, point: &Point2d<N>) -> N {
+        let direction = self.direction();
+        let length_sqr = direction.dot(&direction);
+        if length_sqr == N::zero() {
+            return N::zero();
+        }
+        ((*point - self.start).dot(&direction) / length_sqr)
+            .max(N::zero())
+            .min(N::one())
+    }
+
+    /// The closest point on this segment to `point`.  This is the foot of the perpendicular, unless that's past one
+    /// of the ends.
+    pub fn closest_point(&self, point: &Point2d<N>) -> Point2d<N> {
+        self.point_at(self.closest_parameter(point))
+    }
+
+    /// Intersect two segments with the default tolerance.  See `intersect_with_tolerance`.
+    pub fn intersect(&self, other: &Segment2d<N>) -> Option<Crossing<N>> {
+        self.intersect_with_tolerance(other, default_tolerance())
+    }
+
+    /// Intersect two segments.  `t` is the parameter along this segment, and `u` along the other.
+    ///
+    /// Each parameter comes from the signed distances of a segment's ends to the other segment's line (the meet of
+    /// the points with the join).  Segments meeting at an angle whose sine is within `tolerance` of zero are
+    /// parallel, and parallel segments closer than `tolerance` are collinear.  Overlapping collinear segments give the first
+    /// shared point along this segment.
+    pub fn intersect_with_tolerance(&self, other: &Segment2d<N>, tolerance: N) -> Option<Crossing<N>> {
+        let t = crossing_parameter(&other.to_vector(), &self.start, self.direction(), tolerance);
+        match t {
+            Some(t) => {
+                let u = crossing_parameter(&self.to_vector(), &other.start, other.direction(), tolerance)?;
+                let inside = |p: N| p >= N::zero() && p <= N::one();
+                if inside(t) && inside(u) {
+                    Some(Crossing { point: self.point_at(t), t, u })
+                } else {
+                    None
+                }
+            },
+            None => self.collinear_overlap(other, tolerance),
+        }
+    }
+
+    /// The first point of overlap between two parallel segments, if they're collinear.
+    fn collinear_overlap(&self, other: &Segment2d<N>, tolerance: N) -> Option<Crossing<N>> {
+        let line = self.line();
+        if line.to_vector().distance_to_point(&other.start.to_bivector()).abs() > tolerance {
+            return None;
+        }
+        let direction = self.direction();
+        let length_sqr = direction.dot(&direction);
+        if length_sqr == N::zero() {
+            // A single point, on a parallel line.
+            let u = other.closest_parameter(&self.start);
+            return (other.point_at(u).distance_to(&self.start) <= tolerance)
+                .then_some(Crossing { point: self.start, t: N::zero(), u });
+        }
+
+        let a = (other.start - self.start).dot(&direction) / length_sqr;
+        let b = (other.end - self.start).dot(&direction) / length_sqr;
+        let t = a.min(b).max(N::zero());
+        if t > a.max(b).min(N::one()) {
+            return None;
+        }
+        let point = self.point_at(t);
+        Some(Crossing { point, t, u: other.closest_parameter(&point) })
+    }
+}
+
+/// The parameter at which `start + direction * t` crosses `line`, or `None` if they're parallel.
+/// The signed distances (the meet with the line) grow linearly along the path, so this is where that hits 0.
+pub(crate) fn crossing_parameter<N: Float>(line: &Vector<N>, start: &Point2d<N>, direction: Direction2d<N>, tolerance: N) -> Option<N> {
+    let distance = line.wedge(&start.to_bivector()).e012;
+    let rate = line.wedge(&direction.to_bivector()).e012;
+    let scale = line.magnitude_sqr().sqrt() * direction.length();
+    if rate.abs() <= tolerance * scale || scale == N::zero() {
+        return None;
+    }
+    Some(-distance / rate)
+}
+
+/// Anything which can transform a bivector can transform a segment.
+impl<N: Float, T: RigidTransformation<Bivector<N>>> RigidTransformation<Segment2d<N>> for T {
+    fn apply(&self, target: &Segment2d<N>) -> Segment2d<N> {
+        Segment2d {
+            start: Point2d::from_bivector(&self.apply(&target.start.to_bivector())),
+            end: Point2d::from_bivector(&self.apply(&target.end.to_bivector())),
+        }
+    }
+}
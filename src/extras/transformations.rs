@@ -1,8 +1,11 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 use num_traits::Float;
 
 use crate::{defs::{vector::Vector, bivector::{Bivector}, trivector::Trivector, multivector::Multivector}, traits::{GeometricProduct, Dagger}};
 
-use super::{angle::Angle, point2d::Point2d};
+use super::{angle::Angle, direction2d::Direction2d, point2d::Point2d};
 
 // BASIC REFLECTIONS //
 
@@ -149,6 +152,17 @@ impl<N: Float> Motor<N> {
         }
     }
 
+    /// Create a motor which translates by `direction`, moving things by its full length.
+    pub fn from_direction(direction: Direction2d<N>) -> Motor<N> {
+        let length = direction.length();
+        if length == N::zero() {
+            return Motor::new(N::zero(), N::zero(), N::zero());
+        }
+        // The motor's point at infinity is a quarter turn counterclockwise from the way it moves things.
+        let axis = direction.perpendicular() / length;
+        Motor::new(axis.x, axis.y, length)
+    }
+
     /// The point at infinity this motor translates towards.
     pub fn get_direction(&self) -> Bivector<N> {
         Bivector {
@@ -163,6 +177,11 @@ impl<N: Float> Motor<N> {
         self.displacement
     }
 
+    /// The motor which undoes this one: the same direction, moving the other way.
+    pub fn inverse(&self) -> Motor<N> {
+        Motor::new(self.x, self.y, -self.displacement)
+    }
+
     /// Get the underlying transformer.
     pub fn get_transformer(&self) -> &Transformer<N> {
         &self.transformer
@@ -190,14 +209,21 @@ pub struct MultiTransform<N: Float> {
     multivector: Multivector<N>
 }
 impl<N: Float> MultiTransform<N> {
+    /// Combine the provided transformers, in order.  Requires the `alloc` feature.
+    #[cfg(feature = "alloc")]
     pub fn new(transformations: Vec<&Transformer<N>>) -> MultiTransform<N> {
-        let mut mv = transformations
-            .first()
-            .unwrap()
-            .multivector
-            .clone();
+        MultiTransform::from_slice(&transformations)
+    }
+
+    /// Combine the provided transformers, in order.  Does not need a heap, so this one works everywhere.
+    /// With no transformers, this is the identity, which leaves everything where it is.
+    pub fn from_slice(transformations: &[&Transformer<N>]) -> MultiTransform<N> {
+        let mut mv = Multivector {
+            scalar: N::one(),
+            ..Multivector::zero()
+        };
 
-        for tr in transformations.iter().skip(1) {
+        for tr in transformations.iter() {
             mv = mv.geo(&tr.multivector);
         }
 
@@ -205,6 +231,14 @@ impl<N: Float> MultiTransform<N> {
             multivector: mv
         }
     }
+
+    /// The transformation which undoes this one.  For a rotor or motor, that's its reverse, so this is the reverse of
+    /// the combined multivector, which also undoes the transformations in the opposite order.
+    pub fn inverse(&self) -> MultiTransform<N> {
+        MultiTransform {
+            multivector: self.multivector.reverse()
+        }
+    }
 }
 impl<N: Float> RigidTransformation<Multivector<N>> for MultiTransform<N> {
     fn apply(&self, target: &Multivector<N>) -> Multivector<N> {
@@ -0,0 +1,235 @@
+use alloc::{vec, vec::Vec};
+
+use core::cmp::Ordering;
+
+use num_traits::Float;
+
+use crate::{defs::{vector::Vector, bivector::Bivector}, traits::OuterProduct};
+
+use super::{clip::clip_polygon_to_all, delaunay::{delaunay, Triangulation}, line2d::Line2d, ordering::{find, sort_points}, point2d::Point2d, polygon2d::Polygon2d, predicates::incircle};
+
+// VORONOI DIAGRAMS //
+// The Voronoi diagram is the dual of the Delaunay triangulation: each Delaunay triangle is a Voronoi vertex (the
+// centre of its circle) and each Delaunay edge is a Voronoi edge, along the perpendicular bisector of its two points.
+// The bisector is the join of the two points, `P ∨ Q`, turned a quarter turn about their midpoint by the inner
+// product with it.  Circle centres are meets of bisectors.
+// Hull edges only have one triangle, so their Voronoi edges go off to the point at infinity (e12 = 0) along the
+// bisector, and the cells of the points on the hull are unbounded.
+// Requires the `alloc` feature.
+
+/// A piece of the boundary between two cells.  Requires the `alloc` feature.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VoronoiEdge<N: Float> {
+    /// The perpendicular bisector of the cell's site and its neighbour, with the site on its left.
+    pub line: Vector<N>,
+    /// Where the edge starts.  Either a normalized point, or the point at infinity it comes in from.
+    pub start: Bivector<N>,
+    /// Where the edge ends.  Either a normalized point, or the point at infinity it leaves towards.
+    pub end: Bivector<N>,
+    /// The index of the site on the other side.
+    pub neighbour: usize,
+}
+
+/// The region which is closer to one site than to any other.  Requires the `alloc` feature.
+///
+/// Its edges run counterclockwise around the site, each starting where the last one ended.  An unbounded cell
+/// starts and ends at points at infinity, and when all of the sites are on one line, the cells are strips, with two
+/// parallel edges.  A lone site's cell is the whole plane, and has no edges.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VoronoiCell<N: Float> {
+    site: usize,
+    edges: Vec<VoronoiEdge<N>>,
+}
+
+// Accessors for voronoi cells //
+
+impl<N: Float> VoronoiCell<N> {
+    /// The index of the point this is the cell of.
+    pub fn site(&self) -> usize {
+        self.site
+    }
+
+    /// The edges, counterclockwise around the site.
+    pub fn edges(&self) -> &[VoronoiEdge<N>] {
+        &self.edges
+    }
+
+    /// Whether the cell is a polygon, rather than going off to infinity.
+    pub fn is_bounded(&self) -> bool {
+        self.edges.first().is_some_and(|edge| edge.start.e12 != N::zero())
+    }
+
+    /// The corners, counterclockwise.  For an unbounded cell, these are a chain in the same form as
+    /// `HalfPlaneRegion::Unbounded`: the first and last are points at infinity.
+    pub fn vertices(&self) -> Vec<Bivector<N>> {
+        let mut vertices: Vec<Bivector<N>> = self.edges.iter().map(|edge| edge.start.clone()).collect();
+        if !self.is_bounded() {
+            if let Some(last) = self.edges.last() {
+                vertices.push(last.end.clone());
+            }
+        }
+        vertices
+    }
+
+    /// The bounded cell as a counterclockwise polygon.  `None` if it's unbounded.
+    pub fn to_polygon(&self) -> Option<Polygon2d<N>> {
+        if !self.is_bounded() {
+            return None;
+        }
+        Some(Polygon2d::new(self.edges.iter().map(|edge| Point2d::from_bivector(&edge.start)).collect()))
+    }
+
+    /// The part of `bounds` in this cell.  This works for unbounded cells too, and is empty if they don't overlap.
+    pub fn clip(&self, bounds: &Polygon2d<N>) -> Polygon2d<N> {
+        let lines: Vec<Vector<N>> = self.edges.iter().map(|edge| edge.line.clone()).collect();
+        clip_polygon_to_all(bounds, &lines)
+    }
+}
+
+// Diagrams //
+
+/// The Voronoi diagram of some points, as one cell for each of them.
+///
+/// Duplicate points share a cell, which is the first one's, and neighbours are always the first of their duplicates.
+/// Where four or more points are on one circle, their cells meet at a single vertex, without any zero length edges.
+pub fn voronoi<N: Float>(points: &[Point2d<N>]) -> Vec<VoronoiCell<N>> {
+    let (order, canonical) = sort_points(points);
+    let triangulation = delaunay(points);
+
+    let mut cells: Vec<VoronoiCell<N>> = if triangulation.is_empty() {
+        collinear_cells(points, &order)
+    } else {
+        dual_cells(&triangulation)
+    };
+
+    for (index, &first) in canonical.iter().enumerate() {
+        if first != index {
+            cells[index] = VoronoiCell { site: index, edges: cells[first].edges.clone() };
+        }
+    }
+    cells
+}
+
+/// The Voronoi diagram of some points, with each cell clipped to `bounds`.  This is the territory of each point
+/// inside the bounds, and is empty for points whose cells don't reach them.
+pub fn voronoi_clipped<N: Float>(points: &[Point2d<N>], bounds: &Polygon2d<N>) -> Vec<Polygon2d<N>> {
+    voronoi(points).iter()
+        .map(|cell| cell.clip(bounds))
+        .collect()
+}
+
+/// The perpendicular bisector of two points, with the first on its left.
+///
+/// The join runs from `p` to `q`, and its inner product with the midpoint is the line through it turned a quarter
+/// turn counterclockwise, so it points to the left of `q - p`, which leaves `p` on the left.
+pub fn bisector<N: Float>(p: &Point2d<N>, q: &Point2d<N>) -> Vector<N> {
+    let two = N::one() + N::one();
+    let midpoint = Point2d::new((p.x + q.x) / two, (p.y + q.y) / two);
+    Point2d::line_between_points(*p, *q).perpendicular_through(&midpoint.to_bivector())
+}
+
+// Helpers //
+
+/// Cells from the Delaunay triangulation, by walking counterclockwise around each point's triangles.
+fn dual_cells<N: Float>(triangulation: &Triangulation<N>) -> Vec<VoronoiCell<N>> {
+    let points = triangulation.points();
+    let triangles = triangulation.triangles();
+    let neighbours = triangulation.neighbours();
+
+    // Triangles on the same circle have the same centre.  Each group uses the one with the lowest index, so the
+    // vertex is the same everywhere it's used, and edges between them (which have no length) can be left out.
+    let mut group: Vec<usize> = (0..triangles.len()).collect();
+    for (t, triangle) in triangles.iter().enumerate() {
+        let [a, b, c] = triangulation.triangle(t);
+        for other in neighbours[t].iter().flatten() {
+            let far = triangles[*other].iter().find(|v| !triangle.contains(v)).unwrap();
+            if incircle(&a, &b, &c, &points[*far]) == Ordering::Equal {
+                let (first, second) = (find(&mut group, t), find(&mut group, *other));
+                group[first.max(second)] = first.min(second);
+            }
+        }
+    }
+    let centres: Vec<Bivector<N>> = (0..triangles.len())
+        .map(|t| {
+            let [a, b, c] = triangulation.triangle(find(&mut group, t));
+            Point2d::from_bivector(&bisector(&a, &b).wedge(&bisector(&b, &c))).to_bivector()
+        })
+        .collect();
+    let vertex = |t: usize| centres[t].clone();
+
+    let mut vertex_triangle: Vec<Option<usize>> = vec![None; points.len()];
+    for (t, triangle) in triangles.iter().enumerate() {
+        for v in triangle {
+            vertex_triangle[*v] = Some(t);
+        }
+    }
+
+    let mut cells: Vec<VoronoiCell<N>> = Vec::with_capacity(points.len());
+    for (v, first) in vertex_triangle.iter().enumerate() {
+        let mut edges: Vec<VoronoiEdge<N>> = Vec::new();
+        let Some(first) = *first else {
+            cells.push(VoronoiCell { site: v, edges });
+            continue;
+        };
+        let corner = |t: usize| triangles[t].iter().position(|&w| w == v).unwrap();
+
+        // Go clockwise to the hull, if there is one, so the walk starts there.
+        let mut start = first;
+        let mut bounded = true;
+        while let Some(previous) = neighbours[start][(corner(start) + 2) % 3] {
+            start = previous;
+            if start == first {
+                break;
+            }
+        }
+        if neighbours[start][(corner(start) + 2) % 3].is_none() {
+            bounded = false;
+            let neighbour = triangles[start][(corner(start) + 1) % 3];
+            let line = bisector(&points[v], &points[neighbour]);
+            let start_at = (-Line2d::from_vector(&line).direction()).to_bivector();
+            edges.push(VoronoiEdge { line, start: start_at, end: vertex(start), neighbour });
+        }
+
+        let mut t = start;
+        loop {
+            let i = corner(t);
+            let neighbour = triangles[t][(i + 2) % 3];
+            let line = bisector(&points[v], &points[neighbour]);
+            match neighbours[t][(i + 1) % 3] {
+                None => {
+                    let end = Line2d::from_vector(&line).direction().to_bivector();
+                    edges.push(VoronoiEdge { line, start: vertex(t), end, neighbour });
+                    break;
+                },
+                Some(next) => {
+                    if centres[next] != centres[t] {
+                        edges.push(VoronoiEdge { line, start: vertex(t), end: vertex(next), neighbour });
+                    }
+                    t = next;
+                    if bounded && t == start {
+                        break;
+                    }
+                },
+            }
+        }
+        cells.push(VoronoiCell { site: v, edges });
+    }
+    cells
+}
+
+/// Cells for points which are all on one line (or fewer than two distinct points), which are strips between the
+/// bisectors of consecutive points.  `order` is the points without duplicates, left to right.
+fn collinear_cells<N: Float>(points: &[Point2d<N>], order: &[usize]) -> Vec<VoronoiCell<N>> {
+    let mut cells: Vec<VoronoiCell<N>> = (0..points.len()).map(|site| VoronoiCell { site, edges: Vec::new() }).collect();
+    for (position, &v) in order.iter().enumerate() {
+        let mut edges: Vec<VoronoiEdge<N>> = Vec::with_capacity(2);
+        let before = position.checked_sub(1).map(|p| order[p]);
+        for neighbour in [order.get(position + 1).copied(), before].into_iter().flatten() {
+            let line = bisector(&points[v], &points[neighbour]);
+            let direction = Line2d::from_vector(&line).direction();
+            edges.push(VoronoiEdge { line, start: (-direction).to_bivector(), end: direction.to_bivector(), neighbour });
+        }
+        cells[v].edges = edges;
+    }
+    cells
+}
@@ -1,3 +1,16 @@
+// Everything in here is plain arithmetic, so the standard library is optional.
+// See the `std`, `libm`, and `alloc` features in Cargo.toml.
+#![cfg_attr(not(feature = "std"), no_std)]
+// Explicit `return`s, borrows and full-precision float literals are this crate's style, so clippy's lints against
+// them are off.
+#![allow(clippy::needless_return, clippy::needless_borrow, clippy::excessive_precision)]
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("simply_2dpga needs float math: enable either the `std` (default) or the `libm` feature.");
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 /// Primary definitions for the crate.  Multivectors and various grade of k-vector can be found in here,
 /// along with an enum wrapper for k-vectors.
 pub mod defs;
@@ -13,5 +26,16 @@ pub mod prelude;
 /// Included as its own module incase someone wants to tweak how its handled.
 pub mod equality;
 
+/// Parsing multivectors and k-vectors from text, via `FromStr`.
+/// Accepts both the notation printed by `Display` and a plain ASCII form like `0.5 + 2e0 - 3e12`.
+pub mod parse;
+
+/// The `mv!` macro, for writing multivectors and k-vectors as literals.
+pub mod macros;
+
+/// A symbolic scalar type, for deriving and printing closed-form formulas.  Requires the `std` feature.
+#[cfg(feature = "std")]
+pub mod symbolic;
+
 /// Unit tests.
 mod tests;
@@ -0,0 +1,150 @@
+use num_traits::{Float, ToPrimitive};
+
+/// Build a multivector or k-vector from a list of terms, picking the narrowest type that fits.
+///
+/// Terms are separated by `,`, `+` or `-`.  A term is a coefficient and a basis element, a bare basis element, or a
+/// bare coefficient (which is a scalar).  Coefficients can be literals, variables, or any expression in parentheses,
+/// and can be separated from the basis by a space or a `*`.  A bare variable is a scalar too, unless it's named like
+/// a basis element.
+/// Basis elements are `e0`, `e1`, `e2`, `e01`, `e20`, `e12` and `e012`, along with any permutation of them
+/// (such as `e02` or `e21`), which gets its sign corrected.
+///
+/// If every term is a vector you get a `Vector`, if every term is a bivector you get a `Bivector`, if every term is
+/// a trivector you get a `Trivector`, and otherwise you get a `Multivector`.  The scalar type is taken from context.
+///
+/// Note: `2e0` with no space is a float literal to rust (2.0), so write `2 e0` or `2*e0` instead.
+///
+/// ```rust
+/// use simply_2dpga::prelude::*;
+/// use simply_2dpga::mv;
+///
+/// let line: Vector<f32> = mv![1 e0, 2 e1, 3 e2];
+/// let point: Bivector<f32> = mv!(2*e20 + 3*e01 + e12);
+/// let mixed: Multivector<f64> = mv![0.5, 2 e0, 3 e1, 7 e12];
+///
+/// // e02 = -e20
+/// let x = 4.0;
+/// let flipped: Bivector<f32> = mv!((x) e02);
+/// assert_eq!(flipped, Bivector { e01: 0.0, e20: -4.0, e12: 0.0 });
+/// ```
+#[macro_export]
+macro_rules! mv {
+    // Separators.  A '-' negates the next term.
+    (@parse $s:tt $v:tt $b:tt $t:tt [$($sg:tt)*] , $($rest:tt)*) => {
+        $crate::mv!(@parse $s $v $b $t [$($sg)*] $($rest)*)
+    };
+    (@parse $s:tt $v:tt $b:tt $t:tt [$($sg:tt)*] + $($rest:tt)*) => {
+        $crate::mv!(@parse $s $v $b $t [$($sg)*] $($rest)*)
+    };
+    (@parse $s:tt $v:tt $b:tt $t:tt [$($sg:tt)*] - $($rest:tt)*) => {
+        $crate::mv!(@parse $s $v $b $t [$($sg)* -] $($rest)*)
+    };
+
+    // Parenthesized coefficients.
+    (@parse $s:tt $v:tt $b:tt $t:tt $sg:tt ($($c:tt)*) * $e:ident $($rest:tt)*) => {
+        $crate::mv!(@basis $s $v $b $t $sg (($($c)*)) $e $($rest)*)
+    };
+    (@parse $s:tt $v:tt $b:tt $t:tt $sg:tt ($($c:tt)*) $e:ident $($rest:tt)*) => {
+        $crate::mv!(@basis $s $v $b $t $sg (($($c)*)) $e $($rest)*)
+    };
+    (@parse [$($s:tt)*] $v:tt $b:tt $t:tt $sg:tt ($($c:tt)*) $($rest:tt)*) => {
+        $crate::mv!(@parse [$($s)* ($sg (($($c)*)))] $v $b $t [] $($rest)*)
+    };
+
+    // Literal coefficients.
+    (@parse $s:tt $v:tt $b:tt $t:tt $sg:tt $c:literal * $e:ident $($rest:tt)*) => {
+        $crate::mv!(@basis $s $v $b $t $sg ($c) $e $($rest)*)
+    };
+    (@parse $s:tt $v:tt $b:tt $t:tt $sg:tt $c:literal $e:ident $($rest:tt)*) => {
+        $crate::mv!(@basis $s $v $b $t $sg ($c) $e $($rest)*)
+    };
+    (@parse [$($s:tt)*] $v:tt $b:tt $t:tt $sg:tt $c:literal $($rest:tt)*) => {
+        $crate::mv!(@parse [$($s)* ($sg ($c))] $v $b $t [] $($rest)*)
+    };
+
+    // Variable coefficients, then bare identifiers.  A bare identifier gets the coefficient `{1}`, so if it isn't a
+    // basis element it can be told apart from a coefficient followed by a misspelt one, and is a scalar variable.
+    (@parse $s:tt $v:tt $b:tt $t:tt $sg:tt $c:ident * $e:ident $($rest:tt)*) => {
+        $crate::mv!(@basis $s $v $b $t $sg ($c) $e $($rest)*)
+    };
+    (@parse $s:tt $v:tt $b:tt $t:tt $sg:tt $c:ident $e:ident $($rest:tt)*) => {
+        $crate::mv!(@basis $s $v $b $t $sg ($c) $e $($rest)*)
+    };
+    (@parse $s:tt $v:tt $b:tt $t:tt $sg:tt $e:ident $($rest:tt)*) => {
+        $crate::mv!(@basis $s $v $b $t $sg {1} $e $($rest)*)
+    };
+
+    // Done parsing.
+    (@parse $s:tt $v:tt $b:tt $t:tt []) => {
+        $crate::mv!(@build $s $v $b $t)
+    };
+    (@parse $s:tt $v:tt $b:tt $t:tt $sg:tt $($rest:tt)+) => {
+        compile_error!(concat!("mv!: could not parse term starting at `", stringify!($($rest)+), "`"))
+    };
+
+    // Sort the basis element into its grade, correcting the sign of permutations.
+    (@basis $s:tt [$($v:tt)*] $b:tt $t:tt [$($sg:tt)*] $c:tt e0 $($rest:tt)*) => { $crate::mv!(@parse $s [$($v)* (e0 [$($sg)*] $c)] $b $t [] $($rest)*) };
+    (@basis $s:tt [$($v:tt)*] $b:tt $t:tt [$($sg:tt)*] $c:tt e1 $($rest:tt)*) => { $crate::mv!(@parse $s [$($v)* (e1 [$($sg)*] $c)] $b $t [] $($rest)*) };
+    (@basis $s:tt [$($v:tt)*] $b:tt $t:tt [$($sg:tt)*] $c:tt e2 $($rest:tt)*) => { $crate::mv!(@parse $s [$($v)* (e2 [$($sg)*] $c)] $b $t [] $($rest)*) };
+    (@basis $s:tt $v:tt [$($b:tt)*] $t:tt [$($sg:tt)*] $c:tt e01 $($rest:tt)*) => { $crate::mv!(@parse $s $v [$($b)* (e01 [$($sg)*] $c)] $t [] $($rest)*) };
+    (@basis $s:tt $v:tt [$($b:tt)*] $t:tt [$($sg:tt)*] $c:tt e10 $($rest:tt)*) => { $crate::mv!(@parse $s $v [$($b)* (e01 [$($sg)* -] $c)] $t [] $($rest)*) };
+    (@basis $s:tt $v:tt [$($b:tt)*] $t:tt [$($sg:tt)*] $c:tt e20 $($rest:tt)*) => { $crate::mv!(@parse $s $v [$($b)* (e20 [$($sg)*] $c)] $t [] $($rest)*) };
+    (@basis $s:tt $v:tt [$($b:tt)*] $t:tt [$($sg:tt)*] $c:tt e02 $($rest:tt)*) => { $crate::mv!(@parse $s $v [$($b)* (e20 [$($sg)* -] $c)] $t [] $($rest)*) };
+    (@basis $s:tt $v:tt [$($b:tt)*] $t:tt [$($sg:tt)*] $c:tt e12 $($rest:tt)*) => { $crate::mv!(@parse $s $v [$($b)* (e12 [$($sg)*] $c)] $t [] $($rest)*) };
+    (@basis $s:tt $v:tt [$($b:tt)*] $t:tt [$($sg:tt)*] $c:tt e21 $($rest:tt)*) => { $crate::mv!(@parse $s $v [$($b)* (e12 [$($sg)* -] $c)] $t [] $($rest)*) };
+    (@basis $s:tt $v:tt $b:tt [$($t:tt)*] [$($sg:tt)*] $c:tt e012 $($rest:tt)*) => { $crate::mv!(@parse $s $v $b [$($t)* (e012 [$($sg)*] $c)] [] $($rest)*) };
+    (@basis $s:tt $v:tt $b:tt [$($t:tt)*] [$($sg:tt)*] $c:tt e120 $($rest:tt)*) => { $crate::mv!(@parse $s $v $b [$($t)* (e012 [$($sg)*] $c)] [] $($rest)*) };
+    (@basis $s:tt $v:tt $b:tt [$($t:tt)*] [$($sg:tt)*] $c:tt e201 $($rest:tt)*) => { $crate::mv!(@parse $s $v $b [$($t)* (e012 [$($sg)*] $c)] [] $($rest)*) };
+    (@basis $s:tt $v:tt $b:tt [$($t:tt)*] [$($sg:tt)*] $c:tt e021 $($rest:tt)*) => { $crate::mv!(@parse $s $v $b [$($t)* (e012 [$($sg)* -] $c)] [] $($rest)*) };
+    (@basis $s:tt $v:tt $b:tt [$($t:tt)*] [$($sg:tt)*] $c:tt e210 $($rest:tt)*) => { $crate::mv!(@parse $s $v $b [$($t)* (e012 [$($sg)* -] $c)] [] $($rest)*) };
+    (@basis $s:tt $v:tt $b:tt [$($t:tt)*] [$($sg:tt)*] $c:tt e102 $($rest:tt)*) => { $crate::mv!(@parse $s $v $b [$($t)* (e012 [$($sg)* -] $c)] [] $($rest)*) };
+    (@basis [$($s:tt)*] $v:tt $b:tt $t:tt $sg:tt {1} $c:ident $($rest:tt)*) => {
+        $crate::mv!(@parse [$($s)* ($sg ($c))] $v $b $t [] $($rest)*)
+    };
+    (@basis $s:tt $v:tt $b:tt $t:tt $sg:tt $c:tt $e:ident $($rest:tt)*) => {
+        compile_error!(concat!("mv!: unknown basis element `", stringify!($e), "`"))
+    };
+
+    // Reduce a stack of signs to whether the term is negative, cancelling pairs of minuses.
+    (@negative [- - $($sg:tt)*]) => { $crate::mv!(@negative [$($sg)*]) };
+    (@negative [-]) => { true };
+    (@negative []) => { false };
+
+    // Pick the narrowest type.
+    (@build [] [$(($f:ident $sg:tt $c:tt))+] [] []) => {{
+        let mut k = $crate::defs::vector::Vector::zero();
+        $( $crate::macros::accumulate(&mut k.$f, $crate::mv!(@negative $sg), $c); )+
+        k
+    }};
+    (@build [] [] [$(($f:ident $sg:tt $c:tt))+] []) => {{
+        let mut k = $crate::defs::bivector::Bivector::zero();
+        $( $crate::macros::accumulate(&mut k.$f, $crate::mv!(@negative $sg), $c); )+
+        k
+    }};
+    (@build [] [] [] [$(($f:ident $sg:tt $c:tt))+]) => {{
+        let mut k = $crate::defs::trivector::Trivector::zero();
+        $( $crate::macros::accumulate(&mut k.$f, $crate::mv!(@negative $sg), $c); )+
+        k
+    }};
+    (@build [$(($ssg:tt $sc:tt))*] [$(($vf:ident $vsg:tt $vc:tt))*] [$(($bf:ident $bsg:tt $bc:tt))*] [$(($tf:ident $tsg:tt $tc:tt))*]) => {{
+        let mut m = $crate::defs::multivector::Multivector::zero();
+        $( $crate::macros::accumulate(&mut m.scalar, $crate::mv!(@negative $ssg), $sc); )*
+        $( $crate::macros::accumulate(&mut m.vector.$vf, $crate::mv!(@negative $vsg), $vc); )*
+        $( $crate::macros::accumulate(&mut m.bivector.$bf, $crate::mv!(@negative $bsg), $bc); )*
+        $( $crate::macros::accumulate(&mut m.trivector.$tf, $crate::mv!(@negative $tsg), $tc); )*
+        m
+    }};
+
+    // Entry point.
+    ($($terms:tt)*) => {
+        $crate::mv!(@parse [] [] [] [] [] $($terms)*)
+    };
+}
+
+/// Adds a term written in `mv!` to a coefficient.  The value is converted to the scalar type being built, which lets 
+/// integer literals like `2` be used.
+#[doc(hidden)]
+pub fn accumulate<N: Float, T: ToPrimitive>(target: &mut N, negative: bool, value: T) {
+    let value = N::from(value).unwrap();
+    *target = if negative { *target - value } else { *target + value };
+}
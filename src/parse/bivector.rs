@@ -0,0 +1,20 @@
+use core::str::FromStr;
+
+use num_traits::Float;
+
+use crate::defs::bivector::Bivector;
+
+use super::{parse_multivector, ParseErrorKind, ParseMultivectorError};
+
+impl<N: Float> FromStr for Bivector<N> {
+    type Err = ParseMultivectorError;
+
+    /// Parse a bivector, such as `{ 1e₀₁, 2e₂₀, 3e₁₂ }` or `e01 - 2e02 + 3e12`.  Any other grade is an error.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mv = parse_multivector::<N>(s)?;
+        if mv.scalar != N::zero() || mv.vector != crate::defs::vector::Vector::zero() || mv.trivector.e012 != N::zero() {
+            return Err(ParseMultivectorError::new(ParseErrorKind::WrongGrade { expected: "bivector (grade 2)" }, 0));
+        }
+        Ok(mv.bivector)
+    }
+}
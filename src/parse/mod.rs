@@ -0,0 +1,326 @@
+// Text parsing for multivectors and k-vectors.
+// Two notations are accepted, and can be mixed freely:
+//  * The form printed by `Display`, e.g. `{ 1e₀, 2e₁, 3e₂ }`. Braces are grouping only, and commas act like '+'.
+//  * A plain ASCII sum of terms, e.g. `0.5 + 2e0 - 3e12 + e012` or `2*e01`.
+// Basis names are sign-normalized, so `e02` is read as `-e20` and `e21` as `-e12`.
+// Repeated indices are multiplied out using the metric: `e11` is `1`, and `e00` is `0`.
+
+pub mod multivector;
+pub mod vector;
+pub mod bivector;
+pub mod trivector;
+
+use core::{fmt::Display, iter::Peekable, str::CharIndices};
+
+use num_traits::Float;
+
+use crate::defs::multivector::Multivector;
+
+/// The longest basis name accepted, not counting the 'e'.
+const MAX_BASIS_LEN: usize = 8;
+
+/// What went wrong while parsing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// There was nothing to parse.
+    Empty,
+    /// A character which doesn't belong in a multivector.
+    UnexpectedChar(char),
+    /// A term was expected, such as after a '+' or a '*'.
+    ExpectedTerm,
+    /// A number which could not be parsed as the scalar type.
+    InvalidNumber,
+    /// A basis name that doesn't exist in 2d PGA, like `e3`, or a bare `e`.
+    InvalidBasis,
+    /// A '{' without a matching '}', or the other way around.
+    UnbalancedBraces,
+    /// Parsed fine, but had components outside of the grade being parsed into.
+    WrongGrade { expected: &'static str },
+}
+
+/// Error returned when parsing a multivector or k-vector from text fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseMultivectorError {
+    kind: ParseErrorKind,
+    position: usize,
+}
+
+impl ParseMultivectorError {
+    pub(crate) fn new(kind: ParseErrorKind, position: usize) -> Self {
+        ParseMultivectorError { kind, position }
+    }
+
+    /// What went wrong.
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// The byte offset into the input where things went wrong.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl Display for ParseMultivectorError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.kind {
+            ParseErrorKind::Empty => write!(f, "nothing to parse"),
+            ParseErrorKind::UnexpectedChar(c) => write!(f, "unexpected character '{}' at position {}", c, self.position),
+            ParseErrorKind::ExpectedTerm => write!(f, "expected a number or basis element at position {}", self.position),
+            ParseErrorKind::InvalidNumber => write!(f, "invalid number at position {}", self.position),
+            ParseErrorKind::InvalidBasis => write!(f, "invalid basis element at position {}; expected e followed by indices 0, 1, or 2", self.position),
+            ParseErrorKind::UnbalancedBraces => write!(f, "unbalanced braces at position {}", self.position),
+            ParseErrorKind::WrongGrade { expected } => write!(f, "expected only {} components", expected),
+        }
+    }
+}
+
+impl core::error::Error for ParseMultivectorError {}
+
+/// The coefficients of a multivector, in the order scalar, e0, e1, e2, e01, e20, e12, e012.
+type Coefficients<N> = [N; 8];
+
+/// Parse any supported notation into a multivector.
+pub(crate) fn parse_multivector<N: Float>(text: &str) -> Result<Multivector<N>, ParseMultivectorError> {
+    let mut parser = Parser { text, chars: text.char_indices().peekable(), depth: 0 };
+    let c = parser.parse_sum()?;
+    Ok(Multivector {
+        scalar: c[0],
+        vector: crate::defs::vector::Vector { e0: c[1], e1: c[2], e2: c[3] },
+        bivector: crate::defs::bivector::Bivector { e01: c[4], e20: c[5], e12: c[6] },
+        trivector: crate::defs::trivector::Trivector { e012: c[7] },
+    })
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    chars: Peekable<CharIndices<'a>>,
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    /// Position of the next character, or the end of the input.
+    fn position(&mut self) -> usize {
+        self.chars.peek().map_or(self.text.len(), |(i, _)| *i)
+    }
+
+    /// Skip whitespace and braces, keeping track of brace depth.
+    fn skip_filler(&mut self) -> Result<(), ParseMultivectorError> {
+        while let Some(&(i, c)) = self.chars.peek() {
+            match c {
+                '{' => self.depth += 1,
+                '}' => {
+                    if self.depth == 0 {
+                        return Err(ParseMultivectorError::new(ParseErrorKind::UnbalancedBraces, i));
+                    }
+                    self.depth -= 1;
+                },
+                c if c.is_whitespace() => {},
+                _ => break,
+            }
+            self.chars.next();
+        }
+        Ok(())
+    }
+
+    /// sum := [sign] term { ('+' | '-' | ',') [sign] term }
+    fn parse_sum<N: Float>(&mut self) -> Result<Coefficients<N>, ParseMultivectorError> {
+        let mut coefficients = [N::zero(); 8];
+        let mut expect_term = true;
+        let mut any_term = false;
+        let mut negative = false;
+
+        loop {
+            self.skip_filler()?;
+            let Some(&(i, c)) = self.chars.peek() else {
+                break;
+            };
+
+            if expect_term {
+                match c {
+                    '+' => { self.chars.next(); },
+                    '-' => { self.chars.next(); negative = !negative; },
+                    _ => {
+                        let (value, index) = self.parse_term::<N>()?;
+                        if let Some(index) = index {
+                            coefficients[index] = if negative {
+                                coefficients[index] - value
+                            } else {
+                                coefficients[index] + value
+                            };
+                        }
+                        negative = false;
+                        expect_term = false;
+                        any_term = true;
+                    }
+                }
+            } else {
+                match c {
+                    '+' | ',' => expect_term = true,
+                    '-' => { expect_term = true; negative = true; },
+                    _ => return Err(ParseMultivectorError::new(ParseErrorKind::UnexpectedChar(c), i)),
+                }
+                self.chars.next();
+            }
+        }
+
+        if self.depth != 0 {
+            return Err(ParseMultivectorError::new(ParseErrorKind::UnbalancedBraces, self.text.len()));
+        }
+        if !any_term {
+            return Err(ParseMultivectorError::new(ParseErrorKind::Empty, 0));
+        }
+        if expect_term {
+            return Err(ParseMultivectorError::new(ParseErrorKind::ExpectedTerm, self.text.len()));
+        }
+        Ok(coefficients)
+    }
+
+    /// term := number [ ['*'] basis ] | basis
+    /// Returns the signed coefficient, and which component it belongs to.  The component is `None` when the
+    /// basis squares to zero, such as `e00`.
+    fn parse_term<N: Float>(&mut self) -> Result<(N, Option<usize>), ParseMultivectorError> {
+        let start = self.position();
+        let Some(&(_, c)) = self.chars.peek() else {
+            return Err(ParseMultivectorError::new(ParseErrorKind::ExpectedTerm, start));
+        };
+
+        if c == 'e' {
+            let (sign, index) = self.parse_basis()?;
+            return Ok((N::from(sign).unwrap(), index));
+        }
+        if !(c.is_ascii_digit() || c == '.' || c == 'i' || c == 'N') {
+            return Err(ParseMultivectorError::new(ParseErrorKind::UnexpectedChar(c), start));
+        }
+
+        let value = self.parse_number::<N>()?;
+
+        // An optional basis may follow the number, with or without a '*' between them.
+        while self.chars.peek().is_some_and(|(_, c)| c.is_whitespace()) {
+            self.chars.next();
+        }
+        match self.chars.peek() {
+            Some(&(_, '*')) => {
+                self.chars.next();
+                while self.chars.peek().is_some_and(|(_, c)| c.is_whitespace()) {
+                    self.chars.next();
+                }
+                if self.chars.peek().map(|(_, c)| *c) != Some('e') {
+                    return Err(ParseMultivectorError::new(ParseErrorKind::ExpectedTerm, self.position()));
+                }
+                let (sign, index) = self.parse_basis()?;
+                Ok((value * N::from(sign).unwrap(), index))
+            },
+            Some(&(_, 'e')) => {
+                let (sign, index) = self.parse_basis()?;
+                Ok((value * N::from(sign).unwrap(), index))
+            },
+            _ => Ok((value, Some(0))),
+        }
+    }
+
+    /// A decimal number, or one of `inf` and `NaN` as printed by `Display`.
+    /// Exponent notation isn't supported, since '2e0' has to mean two times e0.
+    fn parse_number<N: Float>(&mut self) -> Result<N, ParseMultivectorError> {
+        let start = self.position();
+        let mut end = start;
+        while let Some(&(i, c)) = self.chars.peek() {
+            if c.is_ascii_digit() || c == '.' {
+                end = i + c.len_utf8();
+                self.chars.next();
+            } else {
+                break;
+            }
+        }
+        if end == start {
+            for word in ["inf", "NaN"] {
+                if self.text[start..].starts_with(word) {
+                    for _ in 0..word.len() {
+                        self.chars.next();
+                    }
+                    end = start + word.len();
+                    break;
+                }
+            }
+        }
+
+        N::from_str_radix(&self.text[start..end], 10)
+            .map_err(|_| ParseMultivectorError::new(ParseErrorKind::InvalidNumber, start))
+    }
+
+    /// basis := 'e' index { index }, where an index is 0, 1, 2, or one of the subscripts ₀, ₁, ₂.
+    /// Returns the sign picked up from reordering, and which component the basis belongs to.
+    fn parse_basis(&mut self) -> Result<(f32, Option<usize>), ParseMultivectorError> {
+        let start = self.position();
+        self.chars.next(); // The 'e'.
+
+        let mut indices = [0u8; MAX_BASIS_LEN];
+        let mut len = 0;
+        while let Some(&(_, c)) = self.chars.peek() {
+            let index = match c {
+                '0' | '₀' => 0,
+                '1' | '₁' => 1,
+                '2' | '₂' => 2,
+                c if c.is_ascii_digit() || ('₀'..='₉').contains(&c) => {
+                    return Err(ParseMultivectorError::new(ParseErrorKind::InvalidBasis, start));
+                },
+                _ => break,
+            };
+            if len == MAX_BASIS_LEN {
+                return Err(ParseMultivectorError::new(ParseErrorKind::InvalidBasis, start));
+            }
+            indices[len] = index;
+            len += 1;
+            self.chars.next();
+        }
+        if len == 0 {
+            return Err(ParseMultivectorError::new(ParseErrorKind::InvalidBasis, start));
+        }
+
+        Ok(normalize_basis(&mut indices[..len]))
+    }
+}
+
+/// Sort a basis product into canonical order, flipping the sign on every swap, then multiply out repeated indices.
+/// Returns the sign, and the component index the basis belongs to (or `None` if it vanished).
+pub(crate) fn normalize_basis(indices: &mut [u8]) -> (f32, Option<usize>) {
+    let mut sign = 1.0;
+
+    // Bubble sort.  Every swap of two different basis vectors is an anticommutation.
+    for i in 0..indices.len() {
+        for j in 0..indices.len() - 1 - i {
+            if indices[j] > indices[j + 1] {
+                indices.swap(j, j + 1);
+                sign = -sign;
+            }
+        }
+    }
+
+    // Repeated indices are now adjacent. e0e0 = 0, while e1e1 = e2e2 = 1.
+    let mut reduced = [false; 3];
+    let mut i = 0;
+    while i < indices.len() {
+        let index = indices[i];
+        let mut count = 0;
+        while i < indices.len() && indices[i] == index {
+            count += 1;
+            i += 1;
+        }
+        if index == 0 && count > 1 {
+            return (0.0, None);
+        }
+        reduced[index as usize] = count % 2 == 1;
+    }
+
+    let component = match reduced {
+        [false, false, false] => 0,
+        [true, false, false] => 1,
+        [false, true, false] => 2,
+        [false, false, true] => 3,
+        [true, true, false] => 4,
+        [true, false, true] => { sign = -sign; 5 }, // e02 = -e20
+        [false, true, true] => 6,
+        [true, true, true] => 7,
+    };
+    (sign, Some(component))
+}
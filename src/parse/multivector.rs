@@ -0,0 +1,29 @@
+use core::str::FromStr;
+
+use num_traits::Float;
+
+use crate::defs::multivector::Multivector;
+
+use super::{parse_multivector, ParseMultivectorError};
+
+impl<N: Float> FromStr for Multivector<N> {
+    type Err = ParseMultivectorError;
+
+    /// Parse a multivector, either as printed by `Display` or as an ASCII sum of terms.
+    /// ```rust
+    /// use simply_2dpga::prelude::*;
+    /// 
+    /// let mv: Multivector<f32> = "0.5 + 2e0 - 3e12 + e012".parse().unwrap();
+    /// assert_eq!(mv.scalar, 0.5);
+    /// assert_eq!(mv.vector.e0, 2.0);
+    /// assert_eq!(mv.bivector.e12, -3.0);
+    /// assert_eq!(mv.trivector.e012, 1.0);
+    /// 
+    /// // Display output parses back to the same thing.
+    /// let round_trip: Multivector<f32> = mv.to_string().parse().unwrap();
+    /// assert_eq!(round_trip, mv);
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_multivector(s)
+    }
+}
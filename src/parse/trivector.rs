@@ -0,0 +1,20 @@
+use core::str::FromStr;
+
+use num_traits::Float;
+
+use crate::defs::trivector::Trivector;
+
+use super::{parse_multivector, ParseErrorKind, ParseMultivectorError};
+
+impl<N: Float> FromStr for Trivector<N> {
+    type Err = ParseMultivectorError;
+
+    /// Parse a trivector, such as `{ 1e₀₁₂ }` or `-2e021`.  Any other grade is an error.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mv = parse_multivector::<N>(s)?;
+        if mv.scalar != N::zero() || mv.vector != crate::defs::vector::Vector::zero() || mv.bivector != crate::defs::bivector::Bivector::zero() {
+            return Err(ParseMultivectorError::new(ParseErrorKind::WrongGrade { expected: "trivector (grade 3)" }, 0));
+        }
+        Ok(mv.trivector)
+    }
+}
@@ -0,0 +1,20 @@
+use core::str::FromStr;
+
+use num_traits::Float;
+
+use crate::defs::vector::Vector;
+
+use super::{parse_multivector, ParseErrorKind, ParseMultivectorError};
+
+impl<N: Float> FromStr for Vector<N> {
+    type Err = ParseMultivectorError;
+
+    /// Parse a vector, such as `{ 1e₀, 2e₁, 3e₂ }` or `1e0 + 2e1 + 3e2`.  Any other grade is an error.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mv = parse_multivector::<N>(s)?;
+        if mv.scalar != N::zero() || mv.bivector != crate::defs::bivector::Bivector::zero() || mv.trivector.e012 != N::zero() {
+            return Err(ParseMultivectorError::new(ParseErrorKind::WrongGrade { expected: "vector (grade 1)" }, 0));
+        }
+        Ok(mv.vector)
+    }
+}
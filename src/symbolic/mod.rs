@@ -0,0 +1,772 @@
+// A symbolic scalar, for deriving and auditing closed-form products.
+//
+// `Float` requires `Copy`, so a `Sym` is just a handle into a global table of expressions.  Every expression is kept
+// in a canonical form: a sum of terms, each term being a coefficient times a product of atoms raised to integer
+// powers.  Atoms are variables, function calls like `sqrt(..)`, and quotients that couldn't be simplified.
+// Expressions are interned, so two expressions which simplify to the same thing share a handle.
+//
+// The table only ever grows, which is fine for generating formulas, but this isn't meant for use in a hot loop.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::{Debug, Display, Write},
+    num::FpCategory,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+    sync::{Mutex, OnceLock},
+};
+
+use num_traits::{Float, Num, NumCast, One, ToPrimitive, Zero};
+
+use crate::defs::{multivector::Multivector, vector::Vector, bivector::Bivector, trivector::Trivector};
+
+/// A symbolic scalar.  Satisfies `Float`, so it can be used anywhere in this crate in place of `f32` or `f64`, and the
+/// results come out as simplified formulas.
+///
+/// ```rust
+/// use simply_2dpga::prelude::*;
+/// use simply_2dpga::symbolic::Sym;
+///
+/// let a: Vector<Sym> = Vector::symbolic("a");
+/// let b: Vector<Sym> = Vector::symbolic("b");
+/// let product = a.geo(&b);
+///
+/// assert_eq!(product.scalar.to_string(), "a1*b1 + a2*b2");
+/// assert_eq!(product.bivector.e12.to_latex(), "a_{1} b_{2} - a_{2} b_{1}");
+/// ```
+#[derive(Clone, Copy)]
+pub struct Sym(u32);
+
+/// A product of atoms raised to powers, sorted by atom.
+type Monomial = Vec<(u32, i32)>;
+
+/// Whole powers up to this are multiplied out, so they simplify with what's around them.  Anything higher would make
+/// a huge polynomial, so it's kept as a call to `powf`.
+const MAX_EXPANDED_POWER: u32 = 16;
+
+/// A sum of monomials with coefficients, sorted by monomial, with no zero coefficients.
+/// Coefficients are stored as bits so that the whole thing can be hashed.
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct Poly {
+    terms: Vec<(Monomial, u64)>,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum Atom {
+    Var(String),
+    Call(&'static str, Vec<u32>),
+    Quotient(u32, u32),
+}
+
+#[derive(Default)]
+struct Table {
+    atoms: Vec<Atom>,
+    atom_ids: HashMap<Atom, u32>,
+    polys: Vec<Poly>,
+    poly_ids: HashMap<Poly, u32>,
+}
+
+fn table() -> std::sync::MutexGuard<'static, Table> {
+    static TABLE: OnceLock<Mutex<Table>> = OnceLock::new();
+    TABLE.get_or_init(|| Mutex::new(Table::default()))
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn to_bits(c: f64) -> u64 {
+    // -0.0 and 0.0 should intern the same.
+    if c == 0.0 { 0.0f64.to_bits() } else { c.to_bits() }
+}
+
+/// Numerically evaluate a named function.  Used for constant folding and for `Sym::eval`.
+fn call_f64(name: &str, args: &[f64]) -> f64 {
+    let x = args[0];
+    match name {
+        "sqrt" => x.sqrt(),
+        "cbrt" => x.cbrt(),
+        "exp" => x.exp(),
+        "exp2" => x.exp2(),
+        "exp_m1" => x.exp_m1(),
+        "ln" => x.ln(),
+        "ln_1p" => x.ln_1p(),
+        "log2" => x.log2(),
+        "log10" => x.log10(),
+        "sin" => x.sin(),
+        "cos" => x.cos(),
+        "tan" => x.tan(),
+        "asin" => x.asin(),
+        "acos" => x.acos(),
+        "atan" => x.atan(),
+        "sinh" => x.sinh(),
+        "cosh" => x.cosh(),
+        "tanh" => x.tanh(),
+        "asinh" => x.asinh(),
+        "acosh" => x.acosh(),
+        "atanh" => x.atanh(),
+        "abs" => x.abs(),
+        "signum" => x.signum(),
+        "floor" => x.floor(),
+        "ceil" => x.ceil(),
+        "round" => x.round(),
+        "trunc" => x.trunc(),
+        "fract" => x.fract(),
+        "atan2" => x.atan2(args[1]),
+        "powf" => x.powf(args[1]),
+        "log" => x.log(args[1]),
+        "max" => x.max(args[1]),
+        "min" => x.min(args[1]),
+        "rem" => x % args[1],
+        "copysign" => x.copysign(args[1]),
+        #[allow(deprecated)]
+        "abs_sub" => x.abs_sub(args[1]),
+        _ => unreachable!("unknown symbolic function {name}"),
+    }
+}
+
+impl Table {
+    fn intern_poly(&mut self, poly: Poly) -> u32 {
+        if let Some(id) = self.poly_ids.get(&poly) {
+            return *id;
+        }
+        let id = self.polys.len() as u32;
+        self.polys.push(poly.clone());
+        self.poly_ids.insert(poly, id);
+        id
+    }
+
+    fn intern_atom(&mut self, atom: Atom) -> u32 {
+        if let Some(id) = self.atom_ids.get(&atom) {
+            return *id;
+        }
+        let id = self.atoms.len() as u32;
+        self.atoms.push(atom.clone());
+        self.atom_ids.insert(atom, id);
+        id
+    }
+
+    /// Build a polynomial from (possibly unsorted, possibly repeated) terms.
+    fn collect(&mut self, terms: impl IntoIterator<Item = (Monomial, f64)>) -> u32 {
+        let mut sums: BTreeMap<Monomial, f64> = BTreeMap::new();
+        for (monomial, c) in terms {
+            *sums.entry(monomial).or_insert(0.0) += c;
+        }
+        let terms = sums.into_iter()
+            .filter(|(_, c)| *c != 0.0)
+            .map(|(m, c)| (m, to_bits(c)))
+            .collect();
+        self.intern_poly(Poly { terms })
+    }
+
+    fn terms(&self, id: u32) -> impl Iterator<Item = (Monomial, f64)> + '_ {
+        self.polys[id as usize].terms.iter().map(|(m, c)| (m.clone(), f64::from_bits(*c)))
+    }
+
+    fn constant(&mut self, c: f64) -> u32 {
+        self.collect([(Vec::new(), c)])
+    }
+
+    fn atom(&mut self, atom: Atom) -> u32 {
+        let atom = self.intern_atom(atom);
+        self.collect([(vec![(atom, 1)], 1.0)])
+    }
+
+    fn as_constant(&self, id: u32) -> Option<f64> {
+        match self.polys[id as usize].terms.as_slice() {
+            [] => Some(0.0),
+            [(m, c)] if m.is_empty() => Some(f64::from_bits(*c)),
+            _ => None,
+        }
+    }
+
+    fn add(&mut self, a: u32, b: u32) -> u32 {
+        let terms: Vec<_> = self.terms(a).chain(self.terms(b)).collect();
+        self.collect(terms)
+    }
+
+    fn neg(&mut self, a: u32) -> u32 {
+        let terms: Vec<_> = self.terms(a).map(|(m, c)| (m, -c)).collect();
+        self.collect(terms)
+    }
+
+    fn mul(&mut self, a: u32, b: u32) -> u32 {
+        let lhs: Vec<_> = self.terms(a).collect();
+        let rhs: Vec<_> = self.terms(b).collect();
+        let mut terms = Vec::with_capacity(lhs.len() * rhs.len());
+        for (m1, c1) in &lhs {
+            for (m2, c2) in &rhs {
+                terms.push((mul_monomials(m1, m2), c1 * c2));
+            }
+        }
+        self.collect(terms)
+    }
+
+    fn div(&mut self, a: u32, b: u32) -> u32 {
+        if a == b && self.as_constant(a).is_none() {
+            return self.constant(1.0);
+        }
+        let divisor: Vec<_> = self.terms(b).collect();
+        match divisor.as_slice() {
+            // Dividing by a single term just flips the powers of its atoms.
+            [(m, c)] => {
+                let inverse: Monomial = m.iter().map(|(atom, power)| (*atom, -power)).collect();
+                let terms: Vec<_> = self.terms(a)
+                    .map(|(m, c2)| (mul_monomials(&m, &inverse), c2 / c))
+                    .collect();
+                self.collect(terms)
+            },
+            // Dividing by zero.  Let the floats decide what that means.
+            [] => {
+                let terms: Vec<_> = self.terms(a).map(|(m, c)| (m, c / 0.0)).collect();
+                if terms.is_empty() { self.constant(f64::NAN) } else { self.collect(terms) }
+            },
+            _ => {
+                if self.as_constant(a) == Some(0.0) {
+                    return a;
+                }
+                self.atom(Atom::Quotient(a, b))
+            }
+        }
+    }
+
+    fn call(&mut self, name: &'static str, args: &[u32]) -> u32 {
+        let constants: Option<Vec<f64>> = args.iter().map(|a| self.as_constant(*a)).collect();
+        match constants {
+            Some(values) => self.constant(call_f64(name, &values)),
+            None => self.atom(Atom::Call(name, args.to_vec())),
+        }
+    }
+
+    fn eval(&self, id: u32, env: &dyn Fn(&str) -> f64) -> f64 {
+        self.polys[id as usize].terms.iter()
+            .map(|(m, c)| {
+                m.iter().fold(f64::from_bits(*c), |product, (atom, power)| {
+                    product * self.eval_atom(*atom, env).powi(*power)
+                })
+            })
+            .sum()
+    }
+
+    /// Add the variables an expression uses to `names`, without values yet.
+    fn variables(&self, id: u32, names: &mut BTreeMap<String, f64>) {
+        for (monomial, _) in &self.polys[id as usize].terms {
+            for (atom, _) in monomial {
+                match &self.atoms[*atom as usize] {
+                    Atom::Var(name) => {
+                        names.entry(name.clone()).or_insert(f64::NAN);
+                    },
+                    Atom::Call(_, args) => args.iter().for_each(|arg| self.variables(*arg, names)),
+                    Atom::Quotient(a, b) => {
+                        self.variables(*a, names);
+                        self.variables(*b, names);
+                    },
+                }
+            }
+        }
+    }
+
+    fn eval_atom(&self, atom: u32, env: &dyn Fn(&str) -> f64) -> f64 {
+        match &self.atoms[atom as usize] {
+            Atom::Var(name) => env(name),
+            Atom::Call(name, args) => {
+                let values: Vec<f64> = args.iter().map(|a| self.eval(*a, env)).collect();
+                call_f64(name, &values)
+            },
+            Atom::Quotient(a, b) => self.eval(*a, env) / self.eval(*b, env),
+        }
+    }
+}
+
+fn mul_monomials(a: &Monomial, b: &Monomial) -> Monomial {
+    let mut powers: BTreeMap<u32, i32> = BTreeMap::new();
+    for (atom, power) in a.iter().chain(b.iter()) {
+        *powers.entry(*atom).or_insert(0) += power;
+    }
+    powers.into_iter().filter(|(_, p)| *p != 0).collect()
+}
+
+// Rendering //
+
+/// Factor names and powers, for ordering terms when rendering.
+type SortKey = Vec<(String, i32)>;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Style {
+    Text,
+    Latex,
+    Rust,
+}
+
+impl Table {
+    fn render(&self, id: u32, style: Style, out: &mut String) {
+        let terms = &self.polys[id as usize].terms;
+        if terms.is_empty() {
+            out.push_str(if style == Style::Rust { "0.0" } else { "0" });
+            return;
+        }
+        // The stored order depends on when each variable was first seen, so sort by name for stable output.
+        let mut sorted: Vec<(SortKey, Monomial, u64)> = terms.iter()
+            .map(|(monomial, bits)| {
+                let mut factors: Vec<_> = monomial.iter().map(|(atom, power)| (self.atom_key(*atom), *atom, *power)).collect();
+                factors.sort();
+                let key = factors.iter().map(|(name, _, power)| (name.clone(), *power)).collect();
+                (key, factors.into_iter().map(|(_, atom, power)| (atom, power)).collect(), *bits)
+            })
+            .collect();
+        sorted.sort_by(|a, b| a.0.cmp(&b.0));
+
+        for (i, (_, monomial, bits)) in sorted.iter().enumerate() {
+            let c = f64::from_bits(*bits);
+            let magnitude = if i == 0 {
+                if c < 0.0 { out.push('-'); }
+                c.abs()
+            } else {
+                out.push_str(if c < 0.0 { " - " } else { " + " });
+                c.abs()
+            };
+            self.render_term(monomial, magnitude, style, out);
+        }
+    }
+
+    /// Plain text for an atom, used to order factors and terms.
+    fn atom_key(&self, atom: u32) -> String {
+        let mut out = String::new();
+        self.render_atom(atom, Style::Text, &mut out);
+        out
+    }
+
+    fn render_term(&self, monomial: &Monomial, c: f64, style: Style, out: &mut String) {
+        let numerator: Vec<_> = monomial.iter().filter(|(_, p)| *p > 0).copied().collect();
+        let denominator: Vec<_> = monomial.iter().filter(|(_, p)| *p < 0).map(|(a, p)| (*a, -p)).collect();
+
+        let number = |out: &mut String| {
+            if style == Style::Rust { write!(out, "{:?}", c).unwrap() } else { write!(out, "{}", c).unwrap() }
+        };
+
+        if style == Style::Latex && !denominator.is_empty() {
+            out.push_str("\\frac{");
+            if numerator.is_empty() || c != 1.0 { number(out); }
+            if c != 1.0 && !numerator.is_empty() { out.push(' '); }
+            self.render_factors(&numerator, style, out);
+            out.push_str("}{");
+            self.render_factors(&denominator, style, out);
+            out.push('}');
+            return;
+        }
+
+        let mut first = true;
+        if numerator.is_empty() || c != 1.0 {
+            number(out);
+            first = false;
+        }
+        if !numerator.is_empty() {
+            if !first { out.push_str(if style == Style::Latex { " " } else { "*" }); }
+            self.render_factors(&numerator, style, out);
+        }
+        if !denominator.is_empty() {
+            out.push('/');
+            if denominator.len() > 1 || (style == Style::Rust && denominator[0].1 > 1) { out.push('('); }
+            self.render_factors(&denominator, style, out);
+            if denominator.len() > 1 || (style == Style::Rust && denominator[0].1 > 1) { out.push(')'); }
+        }
+    }
+
+    fn render_factors(&self, factors: &[(u32, i32)], style: Style, out: &mut String) {
+        for (i, (atom, power)) in factors.iter().enumerate() {
+            if i > 0 { out.push_str(if style == Style::Latex { " " } else { "*" }); }
+            match style {
+                Style::Rust => {
+                    // No powers in rust, so repeat the factor.
+                    for p in 0..*power {
+                        if p > 0 { out.push('*'); }
+                        self.render_atom(*atom, style, out);
+                    }
+                },
+                Style::Text => {
+                    self.render_atom(*atom, style, out);
+                    if *power != 1 { write!(out, "^{}", power).unwrap(); }
+                },
+                Style::Latex => {
+                    self.render_atom(*atom, style, out);
+                    if *power != 1 { write!(out, "^{{{}}}", power).unwrap(); }
+                },
+            }
+        }
+    }
+
+    fn render_atom(&self, atom: u32, style: Style, out: &mut String) {
+        match &self.atoms[atom as usize] {
+            Atom::Var(name) => {
+                // 'a01' becomes 'a_{01}' in LaTeX.
+                let split = name.trim_end_matches(|c: char| c.is_ascii_digit()).len();
+                if style == Style::Latex && split > 0 && split < name.len() {
+                    write!(out, "{}_{{{}}}", &name[..split], &name[split..]).unwrap();
+                } else {
+                    out.push_str(name);
+                }
+            },
+            Atom::Quotient(a, b) => match style {
+                Style::Latex => {
+                    out.push_str("\\frac{");
+                    self.render(*a, style, out);
+                    out.push_str("}{");
+                    self.render(*b, style, out);
+                    out.push('}');
+                },
+                _ => {
+                    out.push('(');
+                    self.render(*a, style, out);
+                    out.push_str(")/(");
+                    self.render(*b, style, out);
+                    out.push(')');
+                },
+            },
+            Atom::Call(name, args) => match style {
+                Style::Latex if *name == "sqrt" => {
+                    out.push_str("\\sqrt{");
+                    self.render(args[0], style, out);
+                    out.push('}');
+                },
+                Style::Latex => {
+                    match *name {
+                        "sin" | "cos" | "tan" | "sinh" | "cosh" | "tanh" | "exp" | "ln" => write!(out, "\\{}", name).unwrap(),
+                        "asin" | "acos" | "atan" => write!(out, "\\arc{}", &name[1..]).unwrap(),
+                        _ => write!(out, "\\operatorname{{{}}}", name).unwrap(),
+                    }
+                    out.push_str("\\left(");
+                    self.render_args(args, style, out);
+                    out.push_str("\\right)");
+                },
+                Style::Text => {
+                    out.push_str(name);
+                    out.push('(');
+                    self.render_args(args, style, out);
+                    out.push(')');
+                },
+                Style::Rust => {
+                    out.push('(');
+                    self.render(args[0], style, out);
+                    write!(out, ").{}(", name).unwrap();
+                    self.render_args(&args[1..], style, out);
+                    out.push(')');
+                },
+            },
+        }
+    }
+
+    fn render_args(&self, args: &[u32], style: Style, out: &mut String) {
+        for (i, arg) in args.iter().enumerate() {
+            if i > 0 { out.push_str(", "); }
+            self.render(*arg, style, out);
+        }
+    }
+}
+
+// Public API //
+
+impl Sym {
+    /// A named variable.
+    pub fn var(name: &str) -> Sym {
+        Sym(table().atom(Atom::Var(name.to_string())))
+    }
+
+    /// A numeric constant.
+    pub fn constant(value: f64) -> Sym {
+        Sym(table().constant(value))
+    }
+
+    /// The value of this expression, if it doesn't depend on any variables.
+    pub fn as_constant(&self) -> Option<f64> {
+        table().as_constant(self.0)
+    }
+
+    /// Evaluate numerically, looking up each variable by name.
+    ///
+    /// Every variable is looked up before the evaluation starts, with the table unlocked, so `env` is free to build
+    /// and evaluate other expressions.
+    pub fn eval(&self, env: impl Fn(&str) -> f64) -> f64 {
+        let mut values: BTreeMap<String, f64> = BTreeMap::new();
+        table().variables(self.0, &mut values);
+        for (name, value) in values.iter_mut() {
+            *value = env(name);
+        }
+        table().eval(self.0, &|name| values[name])
+    }
+
+    /// Render as LaTeX, with subscripted variable names and `\frac` for division.
+    pub fn to_latex(&self) -> String {
+        let mut out = String::new();
+        table().render(self.0, Style::Latex, &mut out);
+        out
+    }
+
+    /// Render as a rust expression over `f64` variables of the same names.  Handy for generating specialized products.
+    pub fn to_rust(&self) -> String {
+        let mut out = String::new();
+        table().render(self.0, Style::Rust, &mut out);
+        out
+    }
+
+    fn call(self, name: &'static str) -> Sym {
+        Sym(table().call(name, &[self.0]))
+    }
+
+    fn call2(self, name: &'static str, other: Sym) -> Sym {
+        Sym(table().call(name, &[self.0, other.0]))
+    }
+}
+
+/// Plain text, such as `a1*b2 - a2*b1`.
+impl Display for Sym {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut out = String::new();
+        table().render(self.0, Style::Text, &mut out);
+        f.write_str(&out)
+    }
+}
+
+impl Debug for Sym {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Sym({})", self)
+    }
+}
+
+impl From<f64> for Sym {
+    fn from(value: f64) -> Self {
+        Sym::constant(value)
+    }
+}
+
+/// Expressions are interned, so equal handles are equal expressions.  Constants compare like floats.
+impl PartialEq for Sym {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.as_constant(), other.as_constant()) {
+            (Some(a), Some(b)) => a == b,
+            _ => self.0 == other.0,
+        }
+    }
+}
+
+/// Only constants (and identical expressions) can be ordered.
+impl PartialOrd for Sym {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        match (self.as_constant(), other.as_constant()) {
+            (Some(a), Some(b)) => a.partial_cmp(&b),
+            _ if self.0 == other.0 => Some(std::cmp::Ordering::Equal),
+            _ => None,
+        }
+    }
+}
+
+// Arithmetic
+
+impl Add for Sym {
+    type Output = Sym;
+    fn add(self, rhs: Sym) -> Sym {
+        Sym(table().add(self.0, rhs.0))
+    }
+}
+impl Sub for Sym {
+    type Output = Sym;
+    fn sub(self, rhs: Sym) -> Sym {
+        let mut table = table();
+        let negated = table.neg(rhs.0);
+        Sym(table.add(self.0, negated))
+    }
+}
+impl Mul for Sym {
+    type Output = Sym;
+    fn mul(self, rhs: Sym) -> Sym {
+        Sym(table().mul(self.0, rhs.0))
+    }
+}
+impl Div for Sym {
+    type Output = Sym;
+    fn div(self, rhs: Sym) -> Sym {
+        Sym(table().div(self.0, rhs.0))
+    }
+}
+impl Rem for Sym {
+    type Output = Sym;
+    fn rem(self, rhs: Sym) -> Sym {
+        self.call2("rem", rhs)
+    }
+}
+impl Neg for Sym {
+    type Output = Sym;
+    fn neg(self) -> Sym {
+        Sym(table().neg(self.0))
+    }
+}
+
+// Numeric traits needed for `Float`.
+
+impl Zero for Sym {
+    fn zero() -> Self {
+        Sym::constant(0.0)
+    }
+    fn is_zero(&self) -> bool {
+        self.as_constant() == Some(0.0)
+    }
+}
+impl One for Sym {
+    fn one() -> Self {
+        Sym::constant(1.0)
+    }
+}
+impl Num for Sym {
+    type FromStrRadixErr = num_traits::ParseFloatError;
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        f64::from_str_radix(str, radix).map(Sym::constant)
+    }
+}
+impl ToPrimitive for Sym {
+    fn to_i64(&self) -> Option<i64> {
+        self.as_constant().and_then(|c| c.to_i64())
+    }
+    fn to_u64(&self) -> Option<u64> {
+        self.as_constant().and_then(|c| c.to_u64())
+    }
+    fn to_f64(&self) -> Option<f64> {
+        self.as_constant()
+    }
+}
+impl NumCast for Sym {
+    fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+        n.to_f64().map(Sym::constant)
+    }
+}
+
+/// Symbolic expressions are treated as finite, normal, positive numbers by the classification methods.
+/// Functions of symbolic expressions stay symbolic, and functions of constants are folded.
+impl Float for Sym {
+    fn nan() -> Self { Sym::constant(f64::NAN) }
+    fn infinity() -> Self { Sym::constant(f64::INFINITY) }
+    fn neg_infinity() -> Self { Sym::constant(f64::NEG_INFINITY) }
+    fn neg_zero() -> Self { Sym::constant(-0.0) }
+    fn min_value() -> Self { Sym::constant(f64::MIN) }
+    fn min_positive_value() -> Self { Sym::constant(f64::MIN_POSITIVE) }
+    fn max_value() -> Self { Sym::constant(f64::MAX) }
+    fn epsilon() -> Self { Sym::constant(f64::EPSILON) }
+
+    fn is_nan(self) -> bool { self.as_constant().is_some_and(f64::is_nan) }
+    fn is_infinite(self) -> bool { self.as_constant().is_some_and(f64::is_infinite) }
+    fn is_finite(self) -> bool { self.as_constant().is_none_or(f64::is_finite) }
+    fn is_normal(self) -> bool { self.as_constant().is_none_or(f64::is_normal) }
+    fn classify(self) -> FpCategory { self.as_constant().map_or(FpCategory::Normal, f64::classify) }
+    fn is_sign_positive(self) -> bool { self.as_constant().is_none_or(f64::is_sign_positive) }
+    fn is_sign_negative(self) -> bool { self.as_constant().is_some_and(f64::is_sign_negative) }
+
+    fn floor(self) -> Self { self.call("floor") }
+    fn ceil(self) -> Self { self.call("ceil") }
+    fn round(self) -> Self { self.call("round") }
+    fn trunc(self) -> Self { self.call("trunc") }
+    fn fract(self) -> Self { self.call("fract") }
+    fn abs(self) -> Self { self.call("abs") }
+    fn signum(self) -> Self { self.call("signum") }
+    fn sqrt(self) -> Self { self.call("sqrt") }
+    fn cbrt(self) -> Self { self.call("cbrt") }
+    fn exp(self) -> Self { self.call("exp") }
+    fn exp2(self) -> Self { self.call("exp2") }
+    fn exp_m1(self) -> Self { self.call("exp_m1") }
+    fn ln(self) -> Self { self.call("ln") }
+    fn ln_1p(self) -> Self { self.call("ln_1p") }
+    fn log2(self) -> Self { self.call("log2") }
+    fn log10(self) -> Self { self.call("log10") }
+    fn sin(self) -> Self { self.call("sin") }
+    fn cos(self) -> Self { self.call("cos") }
+    fn tan(self) -> Self { self.call("tan") }
+    fn asin(self) -> Self { self.call("asin") }
+    fn acos(self) -> Self { self.call("acos") }
+    fn atan(self) -> Self { self.call("atan") }
+    fn sinh(self) -> Self { self.call("sinh") }
+    fn cosh(self) -> Self { self.call("cosh") }
+    fn tanh(self) -> Self { self.call("tanh") }
+    fn asinh(self) -> Self { self.call("asinh") }
+    fn acosh(self) -> Self { self.call("acosh") }
+    fn atanh(self) -> Self { self.call("atanh") }
+
+    fn log(self, base: Self) -> Self { self.call2("log", base) }
+    fn atan2(self, other: Self) -> Self { self.call2("atan2", other) }
+    fn max(self, other: Self) -> Self { self.call2("max", other) }
+    fn min(self, other: Self) -> Self { self.call2("min", other) }
+    fn copysign(self, sign: Self) -> Self { self.call2("copysign", sign) }
+    fn abs_sub(self, other: Self) -> Self { self.call2("abs_sub", other) }
+
+    fn sin_cos(self) -> (Self, Self) { (self.sin(), self.cos()) }
+    fn mul_add(self, a: Self, b: Self) -> Self { self * a + b }
+    fn recip(self) -> Self { Sym::one() / self }
+    fn hypot(self, other: Self) -> Self { (self * self + other * other).sqrt() }
+
+    fn powi(self, n: i32) -> Self {
+        if n.unsigned_abs() > MAX_EXPANDED_POWER {
+            return self.call2("powf", Sym::constant(n as f64));
+        }
+        // By squaring, so each power only takes a few products.
+        let (mut result, mut base, mut k) = (Sym::one(), self, n.unsigned_abs());
+        while k > 0 {
+            if k & 1 == 1 {
+                result = result * base;
+            }
+            k >>= 1;
+            if k > 0 {
+                base = base * base;
+            }
+        }
+        if n < 0 { result.recip() } else { result }
+    }
+
+    fn powf(self, n: Self) -> Self {
+        match n.as_constant() {
+            Some(p) if p.fract() == 0.0 && p.abs() <= MAX_EXPANDED_POWER as f64 => self.powi(p as i32),
+            Some(0.5) => self.sqrt(),
+            _ => self.call2("powf", n),
+        }
+    }
+
+    fn integer_decode(self) -> (u64, i16, i8) {
+        Float::integer_decode(self.as_constant().unwrap_or(f64::NAN))
+    }
+}
+
+// Symbolic k-vectors and multivectors.
+
+impl Vector<Sym> {
+    /// A vector whose coefficients are the variables `{name}0`, `{name}1` and `{name}2`.
+    pub fn symbolic(name: &str) -> Self {
+        Vector {
+            e0: Sym::var(&format!("{name}0")),
+            e1: Sym::var(&format!("{name}1")),
+            e2: Sym::var(&format!("{name}2")),
+        }
+    }
+}
+
+impl Bivector<Sym> {
+    /// A bivector whose coefficients are the variables `{name}01`, `{name}20` and `{name}12`.
+    pub fn symbolic(name: &str) -> Self {
+        Bivector {
+            e01: Sym::var(&format!("{name}01")),
+            e20: Sym::var(&format!("{name}20")),
+            e12: Sym::var(&format!("{name}12")),
+        }
+    }
+}
+
+impl Trivector<Sym> {
+    /// A trivector whose coefficient is the variable `{name}012`.
+    pub fn symbolic(name: &str) -> Self {
+        Trivector { e012: Sym::var(&format!("{name}012")) }
+    }
+}
+
+impl Multivector<Sym> {
+    /// A multivector whose scalar is the variable `{name}`, and whose other coefficients are named like the
+    /// symbolic k-vectors.
+    pub fn symbolic(name: &str) -> Self {
+        Multivector {
+            scalar: Sym::var(name),
+            vector: Vector::symbolic(name),
+            bivector: Bivector::symbolic(name),
+            trivector: Trivector::symbolic(name),
+        }
+    }
+}
@@ -0,0 +1,25 @@
+// TEST FIXTURES //
+// Shapes and assertions shared by the tests.
+
+#[cfg(feature = "alloc")]
+use alloc::vec;
+
+use crate::extras::point2d::Point2d;
+#[cfg(feature = "alloc")]
+use crate::extras::polygon2d::Polygon2d;
+
+/// Assert that two points are the same, up to rounding.
+pub fn assert_close(a: Point2d<f64>, b: Point2d<f64>) {
+    assert!(a.distance_to(&b) < 1e-9, "{} != {}", a, b);
+}
+
+/// An axis-aligned square with its bottom left corner at `(x, y)`, counterclockwise.
+#[cfg(feature = "alloc")]
+pub fn square(x: f64, y: f64, size: f64) -> Polygon2d<f64> {
+    Polygon2d::new(vec![
+        Point2d::new(x, y),
+        Point2d::new(x + size, y),
+        Point2d::new(x + size, y + size),
+        Point2d::new(x, y + size),
+    ])
+}
@@ -1,5 +1,30 @@
+#[cfg(test)]
+mod helpers;
 mod test_multivector;
 mod test_transform;
 mod test_vector;
 mod test_bivector;
-mod test_k_vector;
\ No newline at end of file
+mod test_k_vector;
+mod test_parse;
+mod test_macros;
+#[cfg(feature = "std")]
+mod test_symbolic;
+mod test_metric;
+mod test_projection;
+mod test_line2d;
+mod test_direction2d;
+mod test_segment2d;
+mod test_circle2d;
+mod test_polygon2d;
+mod test_hull;
+mod test_predicates;
+mod test_clip;
+mod test_half_plane;
+mod test_boolean;
+mod test_delaunay;
+mod test_voronoi;
+mod test_arrangement;
+mod test_minkowski;
+mod test_gjk;
+mod test_sat;
+mod test_raycast;
\ No newline at end of file
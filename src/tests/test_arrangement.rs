@@ -0,0 +1,137 @@
+#[cfg(all(test, feature = "alloc"))]
+mod test_line_arrangements {
+    use alloc::vec::Vec;
+
+    use crate::{
+        defs::vector::Vector,
+        extras::{arrangement::{Arrangement, Location}, point2d::Point2d},
+    };
+
+    /// `a x + b y + c = 0`.
+    fn line(a: f64, b: f64, c: f64) -> Vector<f64> {
+        Vector { e0: c, e1: a, e2: b }
+    }
+
+    /// Every half-edge's twin, next and previous agree with each other, and V - E + F = 1 (one less than on a
+    /// sphere, since the region outside the line at infinity isn't a face).
+    fn assert_valid(arrangement: &Arrangement<f64>) {
+        let half_edges = arrangement.half_edges();
+        for (index, half_edge) in half_edges.iter().enumerate() {
+            assert_eq!(half_edge.twin, index ^ 1);
+            assert_eq!(half_edges[half_edge.next].previous, index);
+            assert_eq!(half_edges[half_edge.next].origin, half_edges[half_edge.twin].origin);
+            if half_edge.face.is_some() {
+                assert_eq!(half_edges[half_edge.next].face, half_edge.face);
+            }
+        }
+        let euler = arrangement.vertices().len() as i64 - (half_edges.len() / 2) as i64 + arrangement.faces().len() as i64;
+        assert_eq!(euler, 1);
+    }
+
+    fn bounded_faces(arrangement: &Arrangement<f64>) -> usize {
+        arrangement.faces().iter().filter(|face| face.bounded).count()
+    }
+
+    #[test]
+    fn test_general_position() {
+        // Three lines make a triangle, surrounded by six unbounded faces.
+        let lines = [line(0.0, 1.0, 0.0), line(1.0, 0.0, 0.0), line(1.0, 1.0, -2.0)];
+        let arrangement = Arrangement::new(&lines);
+        assert_valid(&arrangement);
+        assert_eq!(arrangement.faces().len(), 7);
+        assert_eq!(bounded_faces(&arrangement), 1);
+        // Three meets, and six points at infinity.
+        assert_eq!(arrangement.vertices().iter().filter(|vertex| vertex.e12 != 0.0).count(), 3);
+        assert_eq!(arrangement.vertices().iter().filter(|vertex| vertex.e12 == 0.0).count(), 6);
+
+        let Location::Face(triangle) = arrangement.locate(&Point2d::new(0.5, 0.5)) else { panic!() };
+        assert!(arrangement.faces()[triangle].bounded);
+        let corners: Vec<Point2d<f64>> = arrangement.boundary(triangle).iter()
+            .map(|&e| Point2d::from_bivector(&arrangement.vertices()[arrangement.half_edges()[e].origin]))
+            .collect();
+        assert_eq!(corners.len(), 3);
+        for corner in [Point2d::new(0.0, 0.0), Point2d::new(2.0, 0.0), Point2d::new(0.0, 2.0)] {
+            assert!(corners.contains(&corner));
+        }
+    }
+
+    #[test]
+    fn test_degenerate_lines() {
+        // Three lines through the origin make one vertex, not three.
+        let concurrent = Arrangement::new(&[line(1.0, 0.0, 0.0), line(0.0, 1.0, 0.0), line(1.0, -1.0, 0.0)]);
+        assert_valid(&concurrent);
+        assert_eq!(concurrent.vertices().iter().filter(|vertex| vertex.e12 != 0.0).count(), 1);
+        assert_eq!(concurrent.faces().len(), 6);
+        assert_eq!(bounded_faces(&concurrent), 0);
+
+        // Parallel lines share their points at infinity, so three of them make four strips between two points.
+        let parallel = Arrangement::new(&[line(0.0, 1.0, 0.0), line(0.0, 2.0, -2.0), line(0.0, -1.0, -3.0)]);
+        assert_valid(&parallel);
+        assert_eq!(parallel.vertices().len(), 2);
+        assert_eq!(parallel.faces().len(), 4);
+
+        // The same line twice (even backwards) is one line, and the line at infinity isn't a line in the plane.
+        let repeated = Arrangement::new(&[line(1.0, 1.0, 1.0), line(-2.0, -2.0, -2.0), line(0.0, 0.0, 1.0)]);
+        assert_valid(&repeated);
+        assert_eq!(repeated.faces().len(), 2);
+        assert_eq!(repeated.edges_along(1), repeated.edges_along(0));
+        assert!(repeated.edges_along(2).is_empty());
+
+        // No lines at all is just the plane.
+        let empty: Arrangement<f64> = Arrangement::new(&[]);
+        assert_eq!(empty.faces().len(), 1);
+        assert_eq!(empty.locate(&Point2d::new(1.0, 2.0)), Location::Face(0));
+    }
+
+    #[test]
+    fn test_point_location() {
+        // A grid of two horizontal and two vertical lines, with a diagonal through two of its corners.
+        let lines = [
+            line(1.0, 0.0, 0.0),
+            line(1.0, 0.0, -2.0),
+            line(0.0, 1.0, 0.0),
+            line(0.0, 1.0, -2.0),
+            line(1.0, -1.0, 0.0),
+        ];
+        let arrangement = Arrangement::new(&lines);
+        assert_valid(&arrangement);
+        assert_eq!(bounded_faces(&arrangement), 2);
+
+        // The middle square is cut in two by the diagonal.
+        let upper = arrangement.locate(&Point2d::new(0.5, 1.5));
+        let lower = arrangement.locate(&Point2d::new(1.5, 0.5));
+        assert_ne!(upper, lower);
+        assert_eq!(arrangement.locate(&Point2d::new(0.1, 1.9)), upper);
+
+        // Corners the diagonal goes through have three lines through them, and others two.
+        let Location::Vertex(corner) = arrangement.locate(&Point2d::new(2.0, 2.0)) else { panic!() };
+        assert_eq!(arrangement.around(corner).len(), 6);
+        let Location::Vertex(corner) = arrangement.locate(&Point2d::new(0.0, 2.0)) else { panic!() };
+        assert_eq!(arrangement.around(corner).len(), 4);
+
+        // Edges are found pointing the way their line does, which is (-b, a).
+        let Location::Edge(edge) = arrangement.locate(&Point2d::new(1.0, 2.0)) else { panic!() };
+        let half_edge = arrangement.half_edges()[edge];
+        assert_eq!(half_edge.line, Some(3));
+        assert_eq!(Point2d::from_bivector(&arrangement.vertices()[half_edge.origin]), Point2d::new(2.0, 2.0));
+
+        // Outside the grid, the faces go off to infinity.
+        for point in [Point2d::new(1e9, -3.0), Point2d::new(1.0, 7.0), Point2d::new(1.0, -7.0), Point2d::new(-5.0, -6.0)] {
+            let Location::Face(face) = arrangement.locate(&point) else { panic!() };
+            assert!(!arrangement.faces()[face].bounded);
+        }
+    }
+
+    #[test]
+    fn test_locating_below_vertices() {
+        // Straight above or below the corner at (2, 0), the ray looking for the nearest line hits the corner.
+        let arrangement = Arrangement::new(&[line(0.0, 1.0, 0.0), line(1.0, 0.0, 0.0), line(1.0, 1.0, -2.0)]);
+        let below = arrangement.locate(&Point2d::new(2.0, -1.0));
+        assert_eq!(below, arrangement.locate(&Point2d::new(2.5, -1.0)));
+        assert_ne!(below, arrangement.locate(&Point2d::new(3.5, -1.0)));
+
+        let above = arrangement.locate(&Point2d::new(2.0, 1.0));
+        assert_eq!(above, arrangement.locate(&Point2d::new(1.5, 1.0)));
+        assert_ne!(above, arrangement.locate(&Point2d::new(0.5, 1.0)));
+    }
+}
@@ -0,0 +1,132 @@
+#[cfg(all(test, feature = "alloc"))]
+mod test_polygon_booleans {
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use crate::extras::{
+        boolean::{difference, intersection, union, xor, PolygonWithHoles},
+        point2d::Point2d,
+        polygon2d::{Polygon2d, Winding},
+    };
+    use crate::tests::helpers::square;
+
+    /// An axis-aligned square, clockwise so the constructor has something to fix.
+    fn clockwise_square(x: f64, y: f64, size: f64) -> PolygonWithHoles<f64> {
+        PolygonWithHoles::from(square(x, y, size).reversed())
+    }
+
+    fn area(polygons: &[PolygonWithHoles<f64>]) -> f64 {
+        polygons.iter().map(|polygon| polygon.area()).sum()
+    }
+
+    fn contains(polygons: &[PolygonWithHoles<f64>], x: f64, y: f64) -> bool {
+        polygons.iter().any(|polygon| polygon.contains(&Point2d::new(x, y)))
+    }
+
+    #[test]
+    fn test_construction() {
+        let polygon = clockwise_square(0.0, 0.0, 2.0);
+        assert_eq!(polygon.exterior().winding(), Winding::CounterClockwise);
+        assert_eq!(polygon.area(), 4.0);
+
+        let with_hole = PolygonWithHoles::new(polygon.exterior().clone(), vec![polygon.exterior().clone()]);
+        assert_eq!(with_hole.holes()[0].winding(), Winding::Clockwise);
+        assert_eq!(with_hole.area(), 0.0);
+    }
+
+    #[test]
+    fn test_overlapping_squares() {
+        let a = [clockwise_square(0.0, 0.0, 2.0)];
+        let b = [clockwise_square(1.0, 1.0, 2.0)];
+
+        let both = union(&a, &b);
+        assert_eq!(both.len(), 1);
+        assert_eq!(both[0].exterior().len(), 8);
+        assert_eq!(area(&both), 7.0);
+
+        let overlap = intersection(&a, &b);
+        assert_eq!(overlap.len(), 1);
+        assert_eq!(overlap[0].exterior().len(), 4);
+        assert_eq!(area(&overlap), 1.0);
+        assert!(contains(&overlap, 1.5, 1.5));
+
+        let left = difference(&a, &b);
+        assert_eq!(left.len(), 1);
+        assert_eq!(left[0].exterior().len(), 6);
+        assert_eq!(area(&left), 3.0);
+        assert!(!contains(&left, 1.5, 1.5));
+
+        // The two L shapes only touch at their corners, so they're separate.
+        let either = xor(&a, &b);
+        assert_eq!(either.len(), 2);
+        assert_eq!(area(&either), 6.0);
+        assert!(contains(&either, 0.5, 0.5) && contains(&either, 2.5, 2.5) && !contains(&either, 1.5, 1.5));
+    }
+
+    #[test]
+    fn test_shared_edges() {
+        // Side by side, so the shared edge disappears, along with the vertices in the middle of the long edges.
+        let merged = union(&[clockwise_square(0.0, 0.0, 1.0)], &[clockwise_square(1.0, 0.0, 1.0)]);
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged[0].exterior().len(), 4);
+        assert_eq!(area(&merged), 2.0);
+
+        // Nothing overlaps, so there's nothing left.
+        assert!(intersection(&[clockwise_square(0.0, 0.0, 1.0)], &[clockwise_square(1.0, 0.0, 1.0)]).is_empty());
+
+        // Cutting away the same square leaves nothing.
+        assert!(difference(&[clockwise_square(0.0, 0.0, 1.0)], &[clockwise_square(0.0, 0.0, 1.0)]).is_empty());
+
+        let separate = union(&[clockwise_square(0.0, 0.0, 1.0)], &[clockwise_square(3.0, 0.0, 1.0)]);
+        assert_eq!(separate.len(), 2);
+    }
+
+    #[test]
+    fn test_holes() {
+        // Carving a square out of the middle leaves a hole.
+        let level = [clockwise_square(0.0, 0.0, 4.0)];
+        let carved = difference(&level, &[clockwise_square(1.0, 1.0, 2.0)]);
+        assert_eq!(carved.len(), 1);
+        assert_eq!(carved[0].holes().len(), 1);
+        assert_eq!(carved[0].holes()[0].winding(), Winding::Clockwise);
+        assert_eq!(area(&carved), 12.0);
+        assert!(contains(&carved, 0.5, 0.5) && !contains(&carved, 2.0, 2.0));
+
+        // Then a second carve, overlapping the hole and the outside, opens it up.
+        let opened = difference(&carved, &[clockwise_square(2.5, 1.5, 2.0)]);
+        assert_eq!(opened.len(), 1);
+        assert!(opened[0].holes().is_empty());
+        // Only the part which isn't already in the hole comes off.
+        assert_eq!(area(&opened), 12.0 - 3.0 + 0.75);
+
+        // Filling the hole back in gets rid of it.
+        let filled = union(&carved, &[clockwise_square(1.0, 1.0, 2.0)]);
+        assert_eq!(filled.len(), 1);
+        assert!(filled[0].holes().is_empty());
+        assert_eq!(area(&filled), 16.0);
+
+        // Anything inside the hole isn't in the polygon.
+        assert!(intersection(&carved, &[clockwise_square(1.5, 1.5, 1.0)]).is_empty());
+        let rings: Vec<usize> = xor(&carved, &[clockwise_square(1.5, 1.5, 1.0)]).iter()
+            .map(|polygon| polygon.holes().len())
+            .collect();
+        assert_eq!(rings, vec![1, 0]);
+    }
+
+    #[test]
+    fn test_crossing_edges() {
+        // A triangle poking through the side of a square, so the new vertices are meets of edges.
+        let a = [clockwise_square(0.0, 0.0, 2.0)];
+        let b = [PolygonWithHoles::from(Polygon2d::new(vec![
+            Point2d::new(1.0, 1.0),
+            Point2d::new(3.0, 0.0),
+            Point2d::new(3.0, 2.0),
+        ]))];
+        let overlap = intersection(&a, &b);
+        assert_eq!(overlap.len(), 1);
+        assert!(overlap[0].exterior().vertices().contains(&Point2d::new(2.0, 0.5)));
+        assert!(overlap[0].exterior().vertices().contains(&Point2d::new(2.0, 1.5)));
+        assert!((area(&overlap) - 0.5).abs() < 1e-12);
+        assert!((area(&union(&a, &b)) + area(&overlap) - 4.0 - 2.0).abs() < 1e-12);
+    }
+}
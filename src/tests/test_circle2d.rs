@@ -0,0 +1,200 @@
+#[cfg(test)]
+mod test_circles {
+    use crate::extras::{circle2d::{Circle2d, CircleIntersection}, line2d::Line2d, point2d::Point2d, segment2d::Segment2d};
+    use crate::tests::helpers::assert_close;
+
+    #[test]
+    fn test_containment() {
+        let circle = Circle2d::new(Point2d::new(1.0f32, 1.0), 2.0);
+        assert!(circle.contains(&Point2d::new(2.0, 2.0)));
+        assert!(circle.contains(&Point2d::new(3.0, 1.0)));
+        assert!(!circle.contains(&Point2d::new(3.0, 3.0)));
+
+        assert!(circle.contains_circle(&Circle2d::new(Point2d::new(1.5, 1.0), 1.0)));
+        assert!(!circle.contains_circle(&Circle2d::new(Point2d::new(2.5, 1.0), 1.0)));
+    }
+
+    #[test]
+    fn test_circle_line() {
+        let circle = Circle2d::new(Point2d::new(0.0f64, 0.0), 5.0);
+
+        // y = 3, pointing towards +x.
+        let line = Line2d::from_slope_intercept(0.0, 3.0);
+        match circle.intersect_line(&line) {
+            CircleIntersection::Two(p1, p2) => {
+                assert_close(p1, Point2d::new(-4.0, 3.0));
+                assert_close(p2, Point2d::new(4.0, 3.0));
+            },
+            other => panic!("Expected two points, got {:?}", other),
+        }
+
+        let tangent = Line2d::from_slope_intercept(0.0, 5.0);
+        assert_eq!(circle.intersect_line(&tangent), CircleIntersection::One(Point2d::new(0.0, 5.0)));
+
+        let outside = Line2d::from_slope_intercept(0.0, 6.0);
+        assert_eq!(circle.intersect_line(&outside), CircleIntersection::None);
+    }
+
+    #[test]
+    fn test_circle_segment() {
+        let circle = Circle2d::new(Point2d::new(0.0f64, 0.0), 5.0);
+
+        // Starts inside the circle, so only crosses once.
+        let segment = Segment2d::new(Point2d::new(0.0, 3.0), Point2d::new(10.0, 3.0));
+        match circle.intersect_segment(&segment) {
+            CircleIntersection::One(p) => assert_close(p, Point2d::new(4.0, 3.0)),
+            other => panic!("Expected one point, got {:?}", other),
+        }
+
+        let inside = Segment2d::new(Point2d::new(-1.0, 0.0), Point2d::new(1.0, 0.0));
+        assert_eq!(circle.intersect_segment(&inside), CircleIntersection::None);
+    }
+
+    #[test]
+    fn test_circle_circle() {
+        let c1 = Circle2d::new(Point2d::new(0.0f64, 0.0), 5.0);
+        let c2 = Circle2d::new(Point2d::new(8.0f64, 0.0), 5.0);
+        match c1.intersect_circle(&c2) {
+            CircleIntersection::Two(p1, p2) => {
+                assert_close(p1, Point2d::new(4.0, -3.0));
+                assert_close(p2, Point2d::new(4.0, 3.0));
+            },
+            other => panic!("Expected two points, got {:?}", other),
+        }
+
+        let touching = Circle2d::new(Point2d::new(0.0f64, 7.0), 2.0);
+        match c1.intersect_circle(&touching) {
+            CircleIntersection::One(p) => assert_close(p, Point2d::new(0.0, 5.0)),
+            other => panic!("Expected one point, got {:?}", other),
+        }
+
+        let nested = Circle2d::new(Point2d::new(1.0f64, 0.0), 1.0);
+        assert_eq!(c1.intersect_circle(&nested), CircleIntersection::None);
+        assert_eq!(c1.intersect_circle(&c1), CircleIntersection::Coincident);
+    }
+
+    #[test]
+    fn test_tangents() {
+        let circle = Circle2d::new(Point2d::new(0.0f64, 0.0), 1.0);
+        let point = Point2d::new(2.0, 0.0);
+
+        let (t1, t2) = circle.tangents_from(&point).unwrap();
+        for tangent in [t1, t2] {
+            // Each line is at the radius from the center, and passes through the point.
+            assert!((tangent.to_vector().distance_to_point(&circle.center.to_bivector()).abs() - 1.0).abs() < 1e-9);
+            assert!(tangent.to_vector().distance_to_point(&point.to_bivector()).abs() < 1e-9);
+        }
+        // The circle is on the right of the first line (the side its normal points to), and the left of the second.
+        assert!(t1.to_vector().distance_to_point(&circle.center.to_bivector()) > 0.0);
+        assert!(t2.to_vector().distance_to_point(&circle.center.to_bivector()) < 0.0);
+
+        assert_eq!(circle.tangents_from(&Point2d::new(0.5, 0.0)), None);
+    }
+}
+
+#[cfg(test)]
+mod test_arcs {
+    use core::f64::consts::{FRAC_PI_2, PI};
+
+    use crate::extras::{angle::Angle, arc2d::Arc2d, circle2d::{Circle2d, CircleIntersection}, line2d::Line2d, point2d::Point2d};
+
+    #[test]
+    fn test_contains_angle() {
+        // The upper half, from 0 to π, counterclockwise.
+        let upper = Arc2d::new(Point2d::new(0.0f64, 0.0), 1.0, Angle::from_radians(0.0), Angle::from_radians(PI));
+        assert!(upper.contains_angle(Angle::from_radians(FRAC_PI_2)));
+        assert!(upper.contains_angle(Angle::from_radians(FRAC_PI_2 + 2.0 * PI)));
+        assert!(!upper.contains_angle(Angle::from_radians(-FRAC_PI_2)));
+
+        // The same half, swept the other way from π.
+        let clockwise = Arc2d::new(Point2d::new(0.0f64, 0.0), 1.0, Angle::from_radians(PI), Angle::from_radians(-PI));
+        assert!(clockwise.contains_angle(Angle::from_radians(FRAC_PI_2)));
+        assert!(!clockwise.contains_angle(Angle::from_radians(-FRAC_PI_2)));
+    }
+
+    #[test]
+    fn test_arc_intersections() {
+        let upper = Arc2d::new(Point2d::new(0.0f64, 0.0), 5.0, Angle::from_radians(0.0), Angle::from_radians(PI));
+
+        // The vertical line x = 3 crosses the circle at (3, -4) and (3, 4), but only the second is on the arc.
+        let line = Line2d::from_points(Point2d::new(3.0, 0.0), Point2d::new(3.0, 1.0));
+        match upper.intersect_line(&line) {
+            CircleIntersection::One(p) => assert!(p.distance_to(&Point2d::new(3.0, 4.0)) < 1e-9),
+            other => panic!("Expected one point, got {:?}", other),
+        }
+
+        let circle = Circle2d::new(Point2d::new(0.0f64, -8.0), 5.0);
+        assert_eq!(upper.intersect_circle(&circle), CircleIntersection::None);
+
+        let lower = Arc2d::new(Point2d::new(8.0f64, 0.0), 5.0, Angle::from_radians(PI), Angle::from_radians(FRAC_PI_2));
+        match upper.intersect_arc(&lower) {
+            CircleIntersection::None => {},
+            other => panic!("Expected no points, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_arcs_on_one_circle() {
+        let center = Point2d::new(0.0f64, 0.0);
+        let arc = |start: f64, sweep: f64| Arc2d::new(center, 2.0, Angle::from_radians(start), Angle::from_radians(sweep));
+        let first = arc(0.0, FRAC_PI_2);
+
+        // Apart, on opposite sides.
+        assert_eq!(first.intersect_arc(&arc(PI, FRAC_PI_2)), CircleIntersection::None);
+
+        // Overlapping, nested, or the same arc swept the other way.
+        assert_eq!(first.intersect_arc(&arc(0.25, FRAC_PI_2)), CircleIntersection::Coincident);
+        assert_eq!(first.intersect_arc(&arc(0.25, 0.5)), CircleIntersection::Coincident);
+        assert_eq!(first.intersect_arc(&arc(FRAC_PI_2, -FRAC_PI_2)), CircleIntersection::Coincident);
+
+        // Touching at one end.
+        match first.intersect_arc(&arc(FRAC_PI_2, FRAC_PI_2)) {
+            CircleIntersection::One(p) => assert!(p.distance_to(&Point2d::new(0.0, 2.0)) < 1e-9),
+            other => panic!("Expected one point, got {:?}", other),
+        }
+
+        // Touching at both ends, going around the rest of the circle.
+        match first.intersect_arc(&arc(FRAC_PI_2, 1.5 * PI)) {
+            CircleIntersection::Two(p, q) => {
+                assert!(p.distance_to(&Point2d::new(2.0, 0.0)) < 1e-9);
+                assert!(q.distance_to(&Point2d::new(0.0, 2.0)) < 1e-9);
+            },
+            other => panic!("Expected two points, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_arc_length_and_ends() {
+        let arc = Arc2d::new(Point2d::new(1.0f64, 1.0), 2.0, Angle::from_radians(0.0), Angle::from_radians(FRAC_PI_2));
+        assert!((arc.length() - PI).abs() < 1e-9);
+        assert!(arc.start_point().distance_to(&Point2d::new(3.0, 1.0)) < 1e-9);
+        assert!(arc.end_point().distance_to(&Point2d::new(1.0, 3.0)) < 1e-9);
+    }
+}
+
+#[cfg(test)]
+mod test_circle_transforms {
+    use core::f64::consts::FRAC_PI_2;
+
+    use crate::extras::{angle::Angle, arc2d::Arc2d, circle2d::Circle2d, direction2d::Direction2d, point2d::Point2d, transformations::{Motor, RigidTransformation, Rotor}};
+
+    #[test]
+    fn test_transform_circle() {
+        let circle = Circle2d::new(Point2d::new(1.0f64, 2.0), 3.0);
+        let moved = Motor::from_direction(Direction2d::new(2.0, -2.0)).apply(&circle);
+        assert!(moved.center.distance_to(&Point2d::new(3.0, 0.0)) < 1e-9);
+        assert_eq!(moved.radius, 3.0);
+    }
+
+    #[test]
+    fn test_transform_arc() {
+        // Rotating an arc about its own center moves where it starts, and nothing else.
+        let arc = Arc2d::new(Point2d::new(1.0f64, 1.0), 2.0, Angle::from_radians(0.0), Angle::from_radians(FRAC_PI_2));
+        let rotor = Rotor::new(arc.center, Angle::from_radians(FRAC_PI_2));
+        let rotated = rotor.apply(&arc);
+
+        assert!(rotated.center.distance_to(&arc.center) < 1e-9);
+        assert!(rotated.start_point().distance_to(&rotor.apply(&arc.start_point())) < 1e-9);
+        assert!(rotated.end_point().distance_to(&rotor.apply(&arc.end_point())) < 1e-9);
+    }
+}
@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod test_segment_clipping {
+    use crate::{defs::vector::Vector, extras::{clip::{clip_segment, clip_segment_to_all}, point2d::Point2d, segment2d::Segment2d}};
+
+    /// The half-planes of the box from (0, 0) to (4, 2), as counterclockwise edges.
+    fn viewport() -> [Vector<f32>; 4] {
+        [
+            Point2d::line_between_points(Point2d::new(0.0, 0.0), Point2d::new(4.0, 0.0)),
+            Point2d::line_between_points(Point2d::new(4.0, 0.0), Point2d::new(4.0, 2.0)),
+            Point2d::line_between_points(Point2d::new(4.0, 2.0), Point2d::new(0.0, 2.0)),
+            Point2d::line_between_points(Point2d::new(0.0, 2.0), Point2d::new(0.0, 0.0)),
+        ]
+    }
+
+    #[test]
+    fn test_single_half_plane() {
+        // Keep x <= 1.  Pointing up, so the left side is -x.
+        let line = Point2d::line_between_points(Point2d::new(1.0f32, 0.0), Point2d::new(1.0, 1.0));
+        let segment = Segment2d::new(Point2d::new(-1.0f32, 0.0), Point2d::new(3.0, 0.0));
+        assert_eq!(clip_segment(&segment, &line), Some(Segment2d::new(Point2d::new(-1.0, 0.0), Point2d::new(1.0, 0.0))));
+
+        let outside = Segment2d::new(Point2d::new(2.0f32, 0.0), Point2d::new(3.0, 5.0));
+        assert_eq!(clip_segment(&outside, &line), None);
+    }
+
+    #[test]
+    fn test_viewport() {
+        let across = Segment2d::new(Point2d::new(-2.0f32, 1.0), Point2d::new(6.0, 1.0));
+        assert_eq!(clip_segment_to_all(&across, &viewport()), Some(Segment2d::new(Point2d::new(0.0, 1.0), Point2d::new(4.0, 1.0))));
+
+        let inside = Segment2d::new(Point2d::new(1.0f32, 1.0), Point2d::new(2.0, 1.5));
+        assert_eq!(clip_segment_to_all(&inside, &viewport()), Some(inside));
+
+        // Crosses the corner's lines, but misses the box.
+        let corner = Segment2d::new(Point2d::new(3.0f32, 4.0), Point2d::new(6.0, 1.0));
+        assert_eq!(clip_segment_to_all(&corner, &viewport()), None);
+
+        // Only touches the corner.
+        let touching = Segment2d::new(Point2d::new(3.0f32, 3.0), Point2d::new(6.0, 0.0));
+        assert_eq!(clip_segment_to_all(&touching, &viewport()), None);
+    }
+
+    #[test]
+    fn test_touching() {
+        // Along the bottom edge is kept, touching from outside isn't.
+        let along = Segment2d::new(Point2d::new(1.0f32, 0.0), Point2d::new(3.0, 0.0));
+        assert_eq!(clip_segment_to_all(&along, &viewport()), Some(along));
+
+        let touching = Segment2d::new(Point2d::new(4.0f32, 1.0), Point2d::new(6.0, 1.0));
+        assert_eq!(clip_segment_to_all(&touching, &viewport()), None);
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod test_polygon_clipping {
+    use crate::extras::{clip::{clip_polygon, clip_polygon_to_all}, point2d::Point2d};
+    use crate::tests::helpers::square;
+
+    #[test]
+    fn test_clip_square() {
+        // Keep everything above the diagonal, y >= x.
+        let diagonal = Point2d::line_between_points(Point2d::new(0.0, 0.0), Point2d::new(1.0, 1.0));
+        let clipped = clip_polygon(&square(0.0, 0.0, 2.0), &diagonal);
+        assert_eq!(clipped.vertices(), &[Point2d::new(0.0, 0.0), Point2d::new(2.0, 2.0), Point2d::new(0.0, 2.0)]);
+        assert_eq!(clipped.area(), 2.0);
+
+        // Cutting off a corner adds a vertex.
+        let corner = Point2d::line_between_points(Point2d::new(0.0, 1.0), Point2d::new(1.0, 0.0));
+        let clipped = clip_polygon(&square(0.0, 0.0, 2.0), &corner);
+        assert_eq!(clipped.len(), 5);
+        assert_eq!(clipped.area(), 3.5);
+    }
+
+    #[test]
+    fn test_touching_and_empty() {
+        // The line only touches a corner from outside.
+        let touching = Point2d::line_between_points(Point2d::new(2.0, 2.0), Point2d::new(3.0, 1.0));
+        assert!(clip_polygon(&square(0.0, 0.0, 2.0), &touching).is_empty());
+
+        // Opposite half-planes leave nothing.
+        let up = Point2d::line_between_points(Point2d::new(1.0, 0.0), Point2d::new(1.0, 1.0));
+        let down = Point2d::line_between_points(Point2d::new(1.5, 1.0), Point2d::new(1.5, 0.0));
+        assert!(clip_polygon_to_all(&square(0.0, 0.0, 2.0), &[up.clone(), down.clone()]).is_empty());
+
+        let slab = clip_polygon_to_all(&square(0.0, 0.0, 2.0), &[up * -1.0, down * -1.0]);
+        assert_eq!(slab.area(), 1.0);
+    }
+}
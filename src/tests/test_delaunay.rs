@@ -0,0 +1,139 @@
+#[cfg(all(test, feature = "alloc"))]
+mod test_triangulation {
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use core::cmp::Ordering;
+
+    use crate::extras::{
+        boolean::PolygonWithHoles,
+        delaunay::{constrained_delaunay, delaunay, triangulate_polygon, Triangulation, TriangulationError},
+        point2d::Point2d,
+        polygon2d::Polygon2d,
+        predicates::{incircle, orient2d, Orientation},
+    };
+    use crate::tests::helpers::square;
+
+    fn area(triangulation: &Triangulation<f64>) -> f64 {
+        (0..triangulation.len()).map(|t| {
+            let [a, b, c] = triangulation.triangle(t);
+            ((b - a).x * (c - a).y - (b - a).y * (c - a).x) / 2.0
+        }).sum()
+    }
+
+    /// Every triangle is counterclockwise, its neighbours point back at it, and no other point is inside its circle.
+    fn assert_valid(triangulation: &Triangulation<f64>) {
+        let points = triangulation.points();
+        for (t, triangle) in triangulation.triangles().iter().enumerate() {
+            let [a, b, c] = triangulation.triangle(t);
+            assert_eq!(orient2d(&a, &b, &c), Orientation::CounterClockwise);
+            for (edge, neighbour) in triangulation.neighbours()[t].iter().enumerate() {
+                if let Some(other) = *neighbour {
+                    assert!(triangulation.neighbours()[other].contains(&Some(t)));
+                    let across = triangulation.triangles()[other].iter().find(|v| !triangle.contains(v)).unwrap();
+                    if !triangulation.is_constrained(t, edge) {
+                        assert_ne!(incircle(&a, &b, &c, &points[*across]), Ordering::Greater);
+                    }
+                }
+            }
+        }
+    }
+
+    fn has_edge(triangulation: &Triangulation<f64>, from: usize, to: usize) -> bool {
+        triangulation.triangles().iter().any(|triangle| triangle.contains(&from) && triangle.contains(&to))
+    }
+
+    #[test]
+    fn test_delaunay() {
+        // A square with a point off centre: the centre point is joined to all four corners.
+        let points = [
+            Point2d::new(0.0, 0.0),
+            Point2d::new(4.0, 0.0),
+            Point2d::new(4.0, 4.0),
+            Point2d::new(0.0, 4.0),
+            Point2d::new(1.0, 2.0),
+        ];
+        let triangulation = delaunay(&points);
+        assert_eq!(triangulation.len(), 4);
+        assert_eq!(area(&triangulation), 16.0);
+        assert_valid(&triangulation);
+        assert!((0..4).all(|corner| has_edge(&triangulation, corner, 4)));
+
+        // A grid, where every square's corners are on one circle.
+        let grid: Vec<Point2d<f64>> = (0..64).map(|i| Point2d::new((i % 8) as f64, (i / 8) as f64)).collect();
+        let triangulation = delaunay(&grid);
+        assert_eq!(triangulation.len(), 2 * 7 * 7);
+        assert_eq!(area(&triangulation), 49.0);
+        assert_valid(&triangulation);
+    }
+
+    #[test]
+    fn test_degenerate_points() {
+        assert!(delaunay::<f64>(&[]).is_empty());
+        assert!(delaunay(&[Point2d::new(0.0, 0.0), Point2d::new(1.0, 1.0), Point2d::new(2.0, 2.0)]).is_empty());
+
+        // Duplicates only appear once, as the first of them.
+        let points = [Point2d::new(0.0, 0.0), Point2d::new(1.0, 0.0), Point2d::new(0.0, 1.0), Point2d::new(1.0, 0.0)];
+        let triangulation = delaunay(&points);
+        assert_eq!(triangulation.triangles().len(), 1);
+        assert!(!triangulation.triangles()[0].contains(&3));
+        assert_eq!(triangulation.neighbours()[0], [None; 3]);
+    }
+
+    #[test]
+    fn test_constrained_edges() {
+        // A wide diamond, whose Delaunay triangulation uses the short diagonal.  Constraining the long one flips it.
+        let points = [Point2d::new(0.0, 0.0), Point2d::new(4.0, -1.0), Point2d::new(8.0, 0.0), Point2d::new(4.0, 1.0)];
+        assert!(has_edge(&delaunay(&points), 1, 3));
+
+        let triangulation = constrained_delaunay(&points, &[[0, 2]]).unwrap();
+        assert!(has_edge(&triangulation, 0, 2) && !has_edge(&triangulation, 1, 3));
+        assert_valid(&triangulation);
+        for (t, triangle) in triangulation.triangles().iter().enumerate() {
+            let edge = triangle.iter().position(|&v| v == 1 || v == 3).unwrap();
+            assert!(triangulation.is_constrained(t, edge));
+        }
+
+        // A cross can't have both its arms as edges.
+        let points = [Point2d::new(0.0, 0.0), Point2d::new(2.0, 0.0), Point2d::new(1.0, 1.0), Point2d::new(1.0, -1.0)];
+        let triangulation = constrained_delaunay(&points, &[[0, 1], [2, 3]]);
+        assert_eq!(triangulation.err(), Some(TriangulationError::CrossingConstraints(2, 3)));
+
+        // An edge through another point is split at it.
+        let points = [Point2d::new(0.0, 0.0), Point2d::new(1.0, 0.0), Point2d::new(2.0, 0.0), Point2d::new(1.0, 1.0)];
+        let triangulation = constrained_delaunay(&points, &[[0, 2]]).unwrap();
+        assert!(has_edge(&triangulation, 0, 1) && has_edge(&triangulation, 1, 2));
+
+        assert_eq!(constrained_delaunay(&points, &[[0, 7]]).err(), Some(TriangulationError::InvalidIndex(7)));
+    }
+
+    #[test]
+    fn test_polygon() {
+        // A square room with a square pillar in it.
+        let room = PolygonWithHoles::new(square(0.0, 0.0, 6.0), vec![square(2.0, 2.0, 2.0)]);
+        let triangulation = triangulate_polygon(&room).unwrap();
+        assert_eq!(area(&triangulation), 32.0);
+        // Eight vertices and one hole makes eight triangles.
+        assert_eq!(triangulation.len(), 8);
+        assert!(has_edge(&triangulation, 4, 5) && has_edge(&triangulation, 0, 1));
+
+        // Every edge next to the pillar or the walls is constrained and has nothing across it.
+        for t in 0..triangulation.len() {
+            for edge in 0..3 {
+                assert_eq!(triangulation.neighbours()[t][edge].is_none(), triangulation.is_constrained(t, edge));
+            }
+        }
+
+        // An L shape, which isn't convex, so the hull's triangles outside it are removed.
+        let l_shape = PolygonWithHoles::from(Polygon2d::new(vec![
+            Point2d::new(0.0, 0.0),
+            Point2d::new(2.0, 0.0),
+            Point2d::new(2.0, 1.0),
+            Point2d::new(1.0, 1.0),
+            Point2d::new(1.0, 2.0),
+            Point2d::new(0.0, 2.0),
+        ]));
+        let triangulation = triangulate_polygon(&l_shape).unwrap();
+        assert_eq!(triangulation.len(), 4);
+        assert_eq!(area(&triangulation), 3.0);
+    }
+}
@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod test_direction_arithmetic {
+    use crate::{defs::bivector::Bivector, extras::{direction2d::Direction2d, point2d::Point2d}};
+
+    #[test]
+    fn test_point_arithmetic() {
+        let p1 = Point2d::new(1.0f32, 2.0);
+        let p2 = Point2d::new(4.0f32, -2.0);
+
+        let d = p2 - p1;
+        assert_eq!(d, Direction2d::new(3.0, -4.0));
+        assert_eq!(d.length(), 5.0);
+        assert_eq!(p1 + d, p2);
+        assert_eq!(p2 - d, p1);
+        assert_eq!(p1 + d * 0.5, Point2d::new(2.5, 0.0));
+    }
+
+    #[test]
+    fn test_vector_operations() {
+        let d1 = Direction2d::new(3.0f32, 4.0);
+        let d2 = Direction2d::new(-1.0f32, 2.0);
+
+        assert_eq!(d1.dot(&d2), 5.0);
+        assert_eq!(d1.perpendicular(), Direction2d::new(-4.0, 3.0));
+        assert_eq!(d1.dot(&d1.perpendicular()), 0.0);
+        assert_eq!(d1.normalized(), Direction2d::new(0.6, 0.8));
+        assert_eq!(d1 + d2, Direction2d::new(2.0, 6.0));
+        assert_eq!(-(d1 - d2), Direction2d::new(-4.0, -2.0));
+        assert_eq!(d1 / 2.0, Direction2d::new(1.5, 2.0));
+    }
+
+    #[test]
+    fn test_bivector_conversions() {
+        let d = Direction2d::new(3.0f32, 4.0);
+        let b: Bivector<f32> = d.into();
+        assert_eq!(b, Bivector { e01: 4.0, e20: 3.0, e12: 0.0 });
+        assert_eq!(Direction2d::from_bivector(&b), d);
+    }
+}
+
+#[cfg(test)]
+mod test_direction_transforms {
+    use crate::extras::{angle::Angle, direction2d::Direction2d, point2d::Point2d, transformations::{Motor, RigidTransformation, Rotor}};
+
+    #[test]
+    fn test_motor_from_direction() {
+        let motor = Motor::from_direction(Direction2d::new(3.0f64, -4.0));
+        let moved = Point2d::from_bivector(&motor.apply(&Point2d::new(1.0, 1.0).to_bivector()));
+
+        assert!((moved.x - 4.0).abs() < 1e-9);
+        assert!((moved.y + 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_zero_direction_motor() {
+        let motor = Motor::from_direction(Direction2d::new(0.0f64, 0.0));
+        let point = Point2d::new(1.0, 1.0).to_bivector();
+        assert_eq!(motor.apply(&point), point);
+    }
+
+    #[test]
+    fn test_transform_direction() {
+        // Rotating a direction doesn't care about the center, and translating it does nothing.
+        let d = Direction2d::new(1.0f32, 0.0);
+        let rotor = Rotor::new(Point2d::new(5.0, 5.0), Angle::from_degrees(90.0));
+        let rotated = rotor.apply(&d);
+        assert!(rotated.x.abs() < 1e-6 && (rotated.y - 1.0).abs() < 1e-6);
+
+        let motor = Motor::new(1.0f32, 2.0, 3.0);
+        assert_eq!(motor.apply(&d), d);
+    }
+}
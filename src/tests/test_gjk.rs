@@ -0,0 +1,97 @@
+#[cfg(all(test, feature = "alloc"))]
+mod test_collisions {
+    use crate::extras::{
+        angle::Angle,
+        capsule2d::Capsule2d,
+        circle2d::Circle2d,
+        direction2d::Direction2d,
+        gjk::{distance, intersects, penetration, proximity, Proximity, Transformed},
+        point2d::Point2d,
+        segment2d::Segment2d,
+        transformations::{Motor, MultiTransform, Rotor},
+    };
+    use crate::tests::helpers::{assert_close, square};
+
+    #[test]
+    fn test_distance_between_polygons() {
+        let a = square(0.0, 0.0, 1.0);
+        let motor = Motor::from_direction(Direction2d::new(3.0, 0.5));
+        let b = Transformed::new(&a, &motor);
+
+        let separation = distance(&a, &b).unwrap();
+        assert!((separation.distance - 2.0).abs() < 1e-9);
+        assert!((Point2d::from_bivector(&separation.a).x - 1.0).abs() < 1e-9);
+        assert!((Point2d::from_bivector(&separation.b).x - 3.0).abs() < 1e-9);
+        assert!(penetration(&a, &b).is_none());
+        assert!(!intersects(&a, &b));
+    }
+
+    #[test]
+    fn test_round_shapes() {
+        let circle = Circle2d::new(Point2d::new(0.0f64, 0.0), 1.0);
+        let capsule = Capsule2d::new(Point2d::new(3.0, -2.0), Point2d::new(3.0, 2.0), 0.5);
+        let separation = distance(&circle, &capsule).unwrap();
+        assert!((separation.distance - 1.5).abs() < 1e-9);
+        assert_close(Point2d::from_bivector(&separation.a), Point2d::new(1.0, 0.0));
+        assert_close(Point2d::from_bivector(&separation.b), Point2d::new(2.5, 0.0));
+
+        // Within the radii, without the cores overlapping.
+        let near = Circle2d::new(Point2d::new(0.0, 3.0), 2.5);
+        let overlap = penetration(&circle, &near).unwrap();
+        assert!((overlap.depth - 0.5).abs() < 1e-9);
+        assert!((overlap.normal.e01 - 1.0).abs() < 1e-9);
+        assert!(intersects(&circle, &near));
+    }
+
+    #[test]
+    fn test_penetration_between_polygons() {
+        let a = square(0.0, 0.0, 2.0);
+        let motor = Motor::from_direction(Direction2d::new(1.5, 0.5));
+        let b = Transformed::new(&a, &motor);
+
+        let overlap = penetration(&a, &b).unwrap();
+        assert!((overlap.depth - 0.5).abs() < 1e-9);
+        assert!((overlap.normal.e20 - 1.0).abs() < 1e-9 && overlap.normal.e12 == 0.0);
+        let (deepest_a, deepest_b) = (Point2d::from_bivector(&overlap.a), Point2d::from_bivector(&overlap.b));
+        assert!((deepest_a.x - 2.0).abs() < 1e-9 && (deepest_b.x - 1.5).abs() < 1e-9);
+
+        // A point inside is pushed out of the nearest side.
+        let overlap = penetration(&a, &Point2d::new(1.8, 1.0)).unwrap();
+        assert!((overlap.depth - 0.2).abs() < 1e-9);
+        assert!((overlap.normal.e20 - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_transformed_shapes() {
+        // A capsule lying along the x axis, stood up by a rotor and moved by a motor.
+        let capsule = Capsule2d::new(Point2d::new(-1.0, 0.0), Point2d::new(1.0, 0.0), 0.25);
+        let rotor = Rotor::new(Point2d::new(0.0, 0.0), Angle::from_radians(core::f64::consts::FRAC_PI_2));
+        let motor = Motor::from_direction(Direction2d::new(5.0, 0.0));
+        let transformation = MultiTransform::from_slice(&[rotor.get_transformer(), motor.get_transformer()]);
+        let moved = Transformed::new(&capsule, &transformation);
+
+        let wall = Segment2d::new(Point2d::new(3.0, -5.0), Point2d::new(3.0, 5.0));
+        let separation = distance(&wall, &moved).unwrap();
+        assert!((separation.distance - 1.75).abs() < 1e-9);
+
+        let floor = Segment2d::new(Point2d::new(0.0, -0.5), Point2d::new(10.0, -0.5));
+        let overlap = penetration(&floor, &moved).unwrap();
+        assert!((overlap.depth - 0.75).abs() < 1e-9);
+        assert!((overlap.normal.e01 - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_touching() {
+        let a = square(0.0, 0.0, 1.0);
+        let b = Transformed::new(&a, &Motor::from_direction(Direction2d::new(1.0, 0.0)));
+        match proximity(&a, &b) {
+            Proximity::Penetrating(overlap) => assert!(overlap.depth.abs() < 1e-9),
+            Proximity::Separated(separation) => panic!("{:?}", separation),
+        }
+
+        let crossing = Segment2d::new(Point2d::new(0.5, -1.0), Point2d::new(0.5, 0.5));
+        assert!(intersects(&a, &crossing));
+        let inside = penetration(&a, &Point2d::new(0.5, 0.5)).unwrap();
+        assert!((inside.depth - 0.5).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,127 @@
+#[cfg(all(test, feature = "alloc"))]
+mod test_half_plane_intersection {
+    use alloc::vec;
+
+    use crate::{defs::{vector::Vector, bivector::Bivector}, extras::{half_plane::{HalfPlaneRegion, intersect_half_planes}, point2d::Point2d, polygon2d::Winding}};
+
+    fn line(x1: f64, y1: f64, x2: f64, y2: f64) -> Vector<f64> {
+        Point2d::line_between_points(Point2d::new(x1, y1), Point2d::new(x2, y2))
+    }
+
+    #[test]
+    fn test_square() {
+        // The unit square's edges, out of order, with a redundant line and a duplicate further out.
+        let lines = [
+            line(1.0, 1.0, 0.0, 1.0),
+            line(0.0, 0.0, 1.0, 0.0),
+            line(3.0, 0.0, 0.0, 3.0),
+            line(0.0, 1.0, 0.0, 0.0),
+            line(1.0, 0.0, 1.0, 1.0),
+            line(0.0, -1.0, 1.0, -1.0),
+        ];
+        match intersect_half_planes(&lines) {
+            HalfPlaneRegion::Bounded(polygon) => {
+                assert_eq!(polygon.len(), 4);
+                assert_eq!(polygon.winding(), Winding::CounterClockwise);
+                assert!((polygon.area() - 1.0).abs() < 1e-12);
+                for vertex in [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)] {
+                    assert!(polygon.vertices().contains(&Point2d::new(vertex.0, vertex.1)));
+                }
+            },
+            other => panic!("Expected a square, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_touching_corner() {
+        // A line through a corner of the triangle, leaving it whole.
+        let lines = [
+            line(0.0, 0.0, 2.0, 0.0),
+            line(2.0, 0.0, 0.0, 2.0),
+            line(0.0, 2.0, 0.0, 0.0),
+            line(-1.0, 1.0, 0.0, 0.0),
+        ];
+        match intersect_half_planes(&lines) {
+            HalfPlaneRegion::Bounded(polygon) => {
+                assert_eq!(polygon.len(), 3);
+                assert!((polygon.area() - 2.0).abs() < 1e-12);
+            },
+            other => panic!("Expected a triangle, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_empty() {
+        // x <= 0 and x >= 1, closed off by the rest of a square.
+        let lines = [
+            line(0.0, 1.0, 0.0, 0.0),
+            line(1.0, 0.0, 1.0, 1.0),
+            line(0.0, 0.0, 1.0, 0.0),
+            line(1.0, 1.0, 0.0, 1.0),
+        ];
+        assert_eq!(intersect_half_planes(&lines.map(|l| l * -1.0)), HalfPlaneRegion::Empty);
+
+        // A triangle cut away by a line.
+        let triangle = [
+            line(0.0, 0.0, 2.0, 0.0),
+            line(2.0, 0.0, 0.0, 2.0),
+            line(0.0, 2.0, 0.0, 0.0),
+            line(0.0, 3.0, 3.0, 0.0),
+        ];
+        assert_eq!(intersect_half_planes(&triangle), HalfPlaneRegion::Empty);
+
+        // Only touching, at a single point.
+        let point = [line(0.0, 0.0, 1.0, 0.0), line(0.0, 0.0, -1.0, 1.0), line(0.0, 0.0, -1.0, -1.0)];
+        assert_eq!(intersect_half_planes(&point), HalfPlaneRegion::Empty);
+    }
+
+    #[test]
+    fn test_unbounded() {
+        assert_eq!(intersect_half_planes::<f64>(&[]), HalfPlaneRegion::Unbounded(vec![]));
+
+        // y >= 0 comes in from -x and leaves towards +x.
+        assert_eq!(
+            intersect_half_planes(&[line(0.0, 0.0, 1.0, 0.0)]),
+            HalfPlaneRegion::Unbounded(vec![
+                Bivector { e01: 0.0, e20: -1.0, e12: 0.0 },
+                Bivector { e01: 0.0, e20: 1.0, e12: 0.0 },
+            ]),
+        );
+
+        // The quadrant x >= 0, y >= 0 has a corner at the origin.
+        assert_eq!(
+            intersect_half_planes(&[line(0.0, 1.0, 0.0, 0.0), line(0.0, 0.0, 1.0, 0.0)]),
+            HalfPlaneRegion::Unbounded(vec![
+                Bivector { e01: 1.0, e20: 0.0, e12: 0.0 },
+                Bivector { e01: 0.0, e20: 0.0, e12: 1.0 },
+                Bivector { e01: 0.0, e20: 1.0, e12: 0.0 },
+            ]),
+        );
+    }
+
+    #[test]
+    fn test_slabs() {
+        // 0 <= y <= 1 is unbounded both ways.  Its two lines meet at infinity.
+        // The chain starts along the top, coming in from +x, then comes back along the bottom.
+        let slab = [line(0.0, 0.0, 1.0, 0.0), line(1.0, 1.0, 0.0, 1.0)];
+        assert_eq!(
+            intersect_half_planes(&slab),
+            HalfPlaneRegion::Unbounded(vec![
+                Bivector { e01: 0.0, e20: 1.0, e12: 0.0 },
+                Bivector { e01: 0.0, e20: -1.0, e12: 0.0 },
+                Bivector { e01: 0.0, e20: 1.0, e12: 0.0 },
+            ]),
+        );
+
+        // Flipped, there's nothing between them, even when they're only opposite up to rounding.
+        assert_eq!(intersect_half_planes(&slab.clone().map(|l| l * -1.0)), HalfPlaneRegion::Empty);
+        let tilted = [line(0.0, 0.0, 1.0, 0.0), line(1.0, 1.0, 0.0, 1.0 + 1e-13)];
+        assert_eq!(intersect_half_planes(&tilted.map(|l| l * -1.0)), HalfPlaneRegion::Empty);
+
+        // Closing one end of the slab with x >= 0.
+        match intersect_half_planes(&[slab[0].clone(), slab[1].clone(), line(0.0, 1.0, 0.0, 0.0)]) {
+            HalfPlaneRegion::Unbounded(vertices) => assert_eq!(vertices.len(), 4),
+            other => panic!("Expected an unbounded region, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+#[cfg(all(test, feature = "alloc"))]
+mod test_convex_hull {
+    use alloc::vec;
+
+    use crate::extras::{hull::{convex_hull, convex_hull_indices}, point2d::Point2d, polygon2d::Winding};
+
+    #[test]
+    fn test_square_with_interior() {
+        let points = [
+            Point2d::new(1.0f32, 1.0),
+            Point2d::new(0.0, 0.0),
+            Point2d::new(2.0, 2.0),
+            Point2d::new(0.0, 2.0),
+            Point2d::new(2.0, 0.0),
+            Point2d::new(0.5, 1.5),
+        ];
+        assert_eq!(convex_hull_indices(&points), vec![1, 4, 2, 3]);
+
+        let hull = convex_hull(&points);
+        assert_eq!(hull.winding(), Winding::CounterClockwise);
+        assert!(hull.is_convex());
+        assert_eq!(hull.area(), 4.0);
+    }
+
+    #[test]
+    fn test_collinear_and_duplicates() {
+        // The midpoints of the edges are dropped, and so are repeats.
+        let points = [
+            Point2d::new(0.0f64, 0.0),
+            Point2d::new(1.0, 0.0),
+            Point2d::new(2.0, 0.0),
+            Point2d::new(2.0, 2.0),
+            Point2d::new(1.0, 1.0),
+            Point2d::new(2.0, 2.0),
+            Point2d::new(0.0, 0.0),
+        ];
+        assert_eq!(convex_hull_indices(&points), vec![0, 2, 3]);
+    }
+
+    #[test]
+    fn test_degenerate_inputs() {
+        let empty: [Point2d<f32>; 0] = [];
+        assert!(convex_hull_indices(&empty).is_empty());
+
+        let same = [Point2d::new(1.0f32, 1.0); 4];
+        assert_eq!(convex_hull_indices(&same), vec![0]);
+
+        let line = [Point2d::new(1.0f32, 1.0), Point2d::new(3.0, 3.0), Point2d::new(0.0, 0.0), Point2d::new(2.0, 2.0)];
+        assert_eq!(convex_hull_indices(&line), vec![2, 1]);
+    }
+}
@@ -0,0 +1,141 @@
+#[cfg(test)]
+mod test_intersections {
+    use crate::{defs::{vector::Vector, bivector::Bivector}, extras::{line2d::{Line2d, LineIntersection}, point2d::Point2d}};
+
+    #[test]
+    fn test_crossing_lines() {
+        // x = 2 and y = -1.
+        let l1 = Line2d::from_vector(&Vector { e0: -2.0f32, e1: 1.0, e2: 0.0 });
+        let l2 = Line2d::from_vector(&Vector { e0: 3.0f32, e1: 0.0, e2: 3.0 });
+
+        assert_eq!(l1.intersect(&l2), LineIntersection::Point(Point2d::new(2.0, -1.0)));
+    }
+
+    #[test]
+    fn test_parallel_lines() {
+        // y = 1 and y = 3.  They meet at infinity, along the x axis.
+        let l1: Vector<f32> = Vector { e0: -1.0, e1: 0.0, e2: 1.0 };
+        let l2: Vector<f32> = Vector { e0: -6.0, e1: 0.0, e2: 2.0 };
+
+        match l1.intersect(&l2) {
+            LineIntersection::Parallel(direction) => {
+                assert_eq!(direction, Bivector { e01: 0.0, e20: -1.0, e12: 0.0 });
+            },
+            other => panic!("Expected parallel lines, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_coincident_lines() {
+        let l1: Vector<f32> = Vector { e0: -1.0, e1: 1.0, e2: 1.0 };
+        let l2: Vector<f32> = Vector { e0: 2.0, e1: -2.0, e2: -2.0 };
+        assert_eq!(l1.intersect(&l2), LineIntersection::Coincident);
+
+        // The line at infinity has no normal, so it's degenerate.
+        let infinity: Vector<f32> = Vector { e0: 1.0, e1: 0.0, e2: 0.0 };
+        assert_eq!(l1.intersect(&infinity), LineIntersection::Coincident);
+    }
+
+    #[test]
+    fn test_tolerance() {
+        // Nearly parallel lines cross very far away.
+        let l1 = Line2d::from_vector(&Vector { e0: 0.0f64, e1: 0.0, e2: 1.0 });
+        let l2 = Line2d::from_vector(&Vector { e0: -1.0f64, e1: 1e-6, e2: 1.0 });
+
+        assert!(matches!(l1.intersect(&l2), LineIntersection::Point(_)));
+        assert!(matches!(l1.intersect_with_tolerance(&l2, 1e-3), LineIntersection::Parallel(_)));
+    }
+
+    #[test]
+    fn test_try_from_bivector() {
+        let ideal: Bivector<f32> = Bivector { e01: 1.0, e20: 1.0, e12: 0.0 };
+        assert_eq!(Point2d::try_from_bivector(&ideal, 1e-6), None);
+
+        let finite: Bivector<f32> = Bivector { e01: 4.0, e20: 2.0, e12: 2.0 };
+        assert_eq!(Point2d::try_from_bivector(&finite, 1e-6), Some(Point2d::new(1.0, 2.0)));
+    }
+}
+
+#[cfg(test)]
+mod test_constructors {
+    use crate::{defs::vector::Vector, extras::{angle::Angle, direction2d::Direction2d, line2d::Line2d, point2d::Point2d}};
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn test_all_constructors_agree() {
+        // y = x + 1, pointing up and to the right.
+        let expected = Line2d::from_points(Point2d::new(0.0f64, 1.0), Point2d::new(1.0, 2.0));
+        let direction = Direction2d::new(1.0, 1.0);
+        let normal = Direction2d::new(1.0, -1.0);
+        let offset = -0.5f64.sqrt();
+
+        let lines = [
+            Line2d::from_slope_intercept(1.0, 1.0),
+            Line2d::from_point_direction(Point2d::new(-1.0, 0.0), direction),
+            Line2d::from_normal_offset(normal, offset),
+            Line2d::from_angle_offset(Angle::from_radians(-core::f64::consts::FRAC_PI_4), offset),
+        ];
+        for line in lines {
+            let (a, b) = (line.to_vector(), expected.to_vector());
+            assert_close(a.e0, b.e0);
+            assert_close(a.e1, b.e1);
+            assert_close(a.e2, b.e2);
+        }
+    }
+
+    #[test]
+    fn test_accessors() {
+        // x = 3, pointing up.
+        let line = Line2d::from_points(Point2d::new(3.0f64, 0.0), Point2d::new(3.0, 5.0));
+
+        // The normal is the direction rotated a quarter turn clockwise, so it points away from the origin.
+        assert_eq!(line.normal(), Direction2d::new(1.0, 0.0));
+        assert_eq!(line.direction(), Direction2d::new(0.0, 1.0));
+        assert_eq!(line.offset(), 3.0);
+        assert_close(line.angle().get_radians(), 0.0);
+    }
+
+    #[test]
+    fn test_vector_conversions() {
+        let vector: Vector<f32> = Vector { e0: 10.0, e1: 3.0, e2: 4.0 };
+        let line: Line2d<f32> = vector.into();
+        assert_eq!(Vector::from(line), Vector { e0: 2.0, e1: 0.6, e2: 0.8 });
+
+        // The line at infinity can't be normalized, so it's left alone.
+        let ideal: Vector<f32> = Vector { e0: 2.0, e1: 0.0, e2: 0.0 };
+        assert_eq!(Line2d::from_vector(&ideal).to_vector(), ideal);
+    }
+}
+
+#[cfg(test)]
+mod test_line_transforms {
+    use crate::{extras::{angle::Angle, line2d::Line2d, point2d::Point2d, transformations::{Motor, RigidTransformation, Rotor}}};
+
+    #[test]
+    fn test_rotor() {
+        // Rotating the x axis a quarter turn about the origin gives the y axis, pointing up.
+        let x_axis = Line2d::from_slope_intercept(0.0f32, 0.0);
+        let rotor = Rotor::new(Point2d::new(0.0, 0.0), Angle::from_degrees(90.0));
+        let rotated = rotor.apply(&x_axis);
+
+        let direction = rotated.direction();
+        assert!(rotated.offset().abs() < 1e-6);
+        assert!(direction.x.abs() < 1e-6 && direction.y > 1.0 - 1e-6);
+    }
+
+    #[test]
+    fn test_motor_keeps_incidence() {
+        // A point on a line is still on the line after they're both moved.
+        let point = Point2d::new(1.0f64, 2.0);
+        let line = Line2d::from_point_direction(point, Line2d::from_slope_intercept(2.0, 0.0).direction());
+        let motor = Motor::new(1.0, 1.0, 3.0);
+
+        let moved_line = motor.apply(&line);
+        let moved_point = motor.apply(&point.to_bivector());
+        assert!(moved_line.to_vector().distance_to_point(&moved_point).abs() < 1e-9);
+        assert!(line.to_vector().distance_to_point(&moved_point).abs() > 1e-3);
+    }
+}
@@ -0,0 +1,51 @@
+#[cfg(test)]
+mod mv_macro {
+    use crate::defs::{multivector::Multivector, vector::Vector, bivector::Bivector, trivector::Trivector};
+
+    #[test]
+    fn test_narrowest_type() {
+        let v: Vector<f32> = crate::mv![1 e0, 2 e1, 3 e2];
+        assert_eq!(v, Vector { e0: 1.0, e1: 2.0, e2: 3.0 });
+
+        let bv: Bivector<f64> = crate::mv!(2*e20 + 3*e01 + e12);
+        assert_eq!(bv, Bivector { e01: 3.0, e20: 2.0, e12: 1.0 });
+
+        let tv: Trivector<f32> = crate::mv!(-2.5 e012);
+        assert_eq!(tv, Trivector { e012: -2.5 });
+    }
+
+    #[test]
+    fn test_multivector() {
+        // Same multivector as in the geometric product test.
+        let mv: Multivector<f32> = crate::mv![0.5, 2 e0, 3 e1, 4 e2, 5 e01, 6 e20, 7 e12, 8 e012];
+        let correct: Multivector<f32> = Multivector {
+            scalar: 0.5,
+            vector: Vector{ e0: 2.0, e1: 3.0, e2: 4.0 },
+            bivector: Bivector {e01: 5.0, e20: 6.0, e12: 7.0},
+            trivector: Trivector {e012: 8.0},
+        };
+        assert_eq!(mv, correct);
+
+        let mv: Multivector<f64> = crate::mv!(1 + 2*e01);
+        assert_eq!(mv, Multivector { scalar: 1.0, ..Multivector::from_bivector(Bivector { e01: 2.0, e20: 0.0, e12: 0.0 }) });
+    }
+
+    #[test]
+    fn test_permutations_and_signs() {
+        let bv: Bivector<f32> = crate::mv!(2 e02 - e21 + 3 e10);
+        assert_eq!(bv, Bivector { e01: -3.0, e20: -2.0, e12: 1.0 });
+
+        let tv: Trivector<f32> = crate::mv!(e021 - e201);
+        assert_eq!(tv, Trivector { e012: -2.0 });
+
+        let x = 1.5;
+        let y = -2.0;
+        let v: Vector<f32> = crate::mv!(x e1 - (y * 2.0) e2 + x*e0);
+        assert_eq!(v, Vector { e0: 1.5, e1: 1.5, e2: 4.0 });
+
+        // Bare variables are scalars.
+        let mv: Multivector<f64> = crate::mv!(x + 2.0 e1 - y);
+        assert_eq!(mv.scalar, 3.5);
+        assert_eq!(mv.vector, Vector { e0: 0.0, e1: 2.0, e2: 0.0 });
+    }
+}
@@ -0,0 +1,55 @@
+#[cfg(test)]
+mod test_distances {
+    use crate::{defs::{vector::Vector, bivector::Bivector}, extras::point2d::Point2d};
+
+    #[test]
+    fn test_point_line_distance() {
+        // The line 3x + 4y - 10 = 0, scaled so it isn't normalized.
+        let line: Vector<f32> = Vector { e0: -20.0, e1: 6.0, e2: 8.0 };
+
+        assert_eq!(Point2d::new(0.0f32, 0.0).distance_to_line(&line), -2.0);
+        assert_eq!(Point2d::new(6.0f32, 3.0).distance_to_line(&line), 4.0);
+        assert_eq!(Point2d::new(2.0f32, 1.0).distance_to_line(&line), 0.0);
+
+        // A point with a non-unit weight.
+        let point: Bivector<f32> = Bivector { e01: 6.0, e20: 12.0, e12: 2.0 };
+        assert_eq!(line.distance_to_point(&point), 4.0);
+    }
+
+    #[test]
+    fn test_point_point_distance() {
+        let p1: Point2d<f32> = Point2d::new(1.0, 2.0);
+        let p2: Point2d<f32> = Point2d::new(4.0, -2.0);
+        assert_eq!(p1.distance_to(&p2), 5.0);
+
+        let b1 = p1.to_bivector() * 3.0;
+        let b2 = p2.to_bivector() * -0.5;
+        assert_eq!(b1.distance_to(&b2), 5.0);
+    }
+
+    #[test]
+    fn test_parallel_distance() {
+        let l1: Vector<f32> = Vector { e0: -1.0, e1: 0.0, e2: 1.0 }; // y = 1
+        let l2: Vector<f32> = Vector { e0: 6.0, e1: 0.0, e2: -2.0 }; // y = 3, opposite orientation
+        assert_eq!(l1.distance_to_parallel(&l2), 2.0);
+        assert_eq!(l2.distance_to_parallel(&l1), 2.0);
+    }
+}
+
+#[cfg(test)]
+mod test_angles {
+    use core::f32::consts::{FRAC_PI_2, FRAC_PI_4};
+
+    use crate::defs::vector::Vector;
+
+    #[test]
+    fn test_line_angle() {
+        let x_axis: Vector<f32> = Vector { e0: 0.0, e1: 0.0, e2: 1.0 };
+        let y_axis: Vector<f32> = Vector { e0: 0.0, e1: 1.0, e2: 0.0 };
+        let diagonal: Vector<f32> = Vector { e0: 3.0, e1: -2.0, e2: 2.0 };
+
+        assert_eq!(x_axis.angle_to(&y_axis).get_radians(), -FRAC_PI_2);
+        assert_eq!(y_axis.angle_to(&x_axis).get_radians(), FRAC_PI_2);
+        assert!((x_axis.angle_to(&diagonal).get_radians() - FRAC_PI_4).abs() < 1e-6);
+    }
+}
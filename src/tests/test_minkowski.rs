@@ -0,0 +1,116 @@
+#[cfg(all(test, feature = "alloc"))]
+mod test_minkowski_sums {
+    use alloc::vec;
+
+    use crate::extras::{
+        boolean::PolygonWithHoles,
+        minkowski::{convex_decomposition, minkowski_difference, minkowski_difference_general, minkowski_sum, minkowski_sum_general},
+        point2d::Point2d,
+        polygon2d::{Polygon2d, Winding},
+    };
+    use crate::tests::helpers::square;
+
+    /// Two wide by two tall, with the top right quarter missing.
+    fn l_shape() -> PolygonWithHoles<f64> {
+        PolygonWithHoles::from(Polygon2d::new(vec![
+            Point2d::new(0.0, 0.0),
+            Point2d::new(2.0, 0.0),
+            Point2d::new(2.0, 1.0),
+            Point2d::new(1.0, 1.0),
+            Point2d::new(1.0, 2.0),
+            Point2d::new(0.0, 2.0),
+        ]))
+    }
+
+    #[test]
+    fn test_convex_sum() {
+        // A square and a triangle, clockwise.  The sum has every edge of both, with the parallel ones merged.
+        let triangle = Polygon2d::new(vec![Point2d::new(0.0, 0.0), Point2d::new(0.0, 1.0), Point2d::new(1.0, 0.0)]);
+        let sum = minkowski_sum(&square(0.0, 0.0, 2.0), &triangle);
+        assert_eq!(sum.winding(), Winding::CounterClockwise);
+        assert_eq!(sum.vertices(), &[
+            Point2d::new(0.0, 0.0),
+            Point2d::new(3.0, 0.0),
+            Point2d::new(3.0, 2.0),
+            Point2d::new(2.0, 3.0),
+            Point2d::new(0.0, 3.0),
+        ]);
+        assert_eq!(sum.area(), 8.5);
+
+        // A point just moves the other polygon.
+        let moved = minkowski_sum(&triangle, &Polygon2d::new(vec![Point2d::new(5.0, 5.0)]));
+        assert_eq!(moved.area(), 0.5);
+        assert!(moved.vertices().contains(&Point2d::new(5.0, 6.0)));
+
+        assert!(minkowski_sum(&triangle, &Polygon2d::new(vec![])).is_empty());
+    }
+
+    #[test]
+    fn test_repeated_vertices() {
+        // The repeated corner is an edge with no length, which mustn't make the walk skip one of the triangle's edges.
+        let square = Polygon2d::new(vec![
+            Point2d::new(0.0, 0.0),
+            Point2d::new(2.0, 0.0),
+            Point2d::new(2.0, 0.0),
+            Point2d::new(2.0, 2.0),
+            Point2d::new(0.0, 2.0),
+            Point2d::new(0.0, 0.0),
+        ]);
+        let triangle = Polygon2d::new(vec![Point2d::new(0.0, 0.0), Point2d::new(1.0, 0.0), Point2d::new(0.0, 1.0)]);
+        let sum = minkowski_sum(&square, &triangle);
+        assert!(sum.is_convex());
+        assert_eq!(sum.area(), 8.5);
+        assert_eq!(sum.len(), 5);
+    }
+
+    #[test]
+    fn test_convex_difference() {
+        // An obstacle, and a unit square moving by its bottom left corner.  Its corner can't get within one of the
+        // obstacle's left or bottom sides, but can get right up to the others.
+        let space = minkowski_difference(&square(2.0, 2.0, 2.0), &square(0.0, 0.0, 1.0));
+        assert_eq!(space.vertices(), &[
+            Point2d::new(1.0, 1.0),
+            Point2d::new(4.0, 1.0),
+            Point2d::new(4.0, 4.0),
+            Point2d::new(1.0, 4.0),
+        ]);
+    }
+
+    #[test]
+    fn test_convex_decomposition() {
+        // Two pieces would do, but which merges are tried first decides how many there are.
+        let pieces = convex_decomposition(&l_shape()).unwrap();
+        assert!((2..=3).contains(&pieces.len()));
+        assert!(pieces.iter().all(|piece| piece.is_convex()));
+        assert_eq!(pieces.iter().map(|piece| piece.area()).sum::<f64>(), 3.0);
+        assert!(pieces.iter().all(|piece| piece.winding() == Winding::CounterClockwise));
+
+        // A square frame can't be one piece, and nothing in it covers the hole.
+        let frame = PolygonWithHoles::new(square(0.0, 0.0, 3.0), vec![square(1.0, 1.0, 1.0)]);
+        let pieces = convex_decomposition(&frame).unwrap();
+        assert!(pieces.len() >= 4);
+        assert_eq!(pieces.iter().map(|piece| piece.area()).sum::<f64>(), 8.0);
+        assert!(pieces.iter().all(|piece| piece.winding_number(&Point2d::new(1.5, 1.5)) == 0));
+    }
+
+    #[test]
+    fn test_general_sum() {
+        // Growing the L shape by a unit square fills in a bit of its corner.
+        let grown = minkowski_sum_general(&l_shape(), &PolygonWithHoles::from(square(0.0, 0.0, 1.0))).unwrap();
+        assert_eq!(grown.len(), 1);
+        assert_eq!(grown[0].area(), 8.0);
+        assert!(grown[0].contains(&Point2d::new(2.5, 1.5)) && !grown[0].contains(&Point2d::new(2.5, 2.5)));
+
+        // A small square closes up a frame's hole, but a smaller one doesn't.
+        let frame = PolygonWithHoles::new(square(0.0, 0.0, 3.0), vec![square(1.0, 1.0, 1.0)]);
+        let closed = minkowski_sum_general(&frame, &PolygonWithHoles::from(square(0.0, 0.0, 1.0))).unwrap();
+        assert_eq!(closed.len(), 1);
+        assert!(closed[0].holes().is_empty());
+        assert_eq!(closed[0].area(), 16.0);
+
+        let open = minkowski_difference_general(&frame, &PolygonWithHoles::from(square(0.0, 0.0, 0.5))).unwrap();
+        assert_eq!(open.len(), 1);
+        assert_eq!(open[0].holes().len(), 1);
+        assert_eq!(open[0].area(), 3.5 * 3.5 - 0.25);
+    }
+}
@@ -1,7 +1,35 @@
 
 #[cfg(test)]
 mod multivector_constructors {
+    use crate::{defs::{multivector::Multivector, vector::Vector, bivector::Bivector, trivector::Trivector}, traits::GeometricProduct, extras::{point2d::Point2d, angle::Angle}};
 
+    // Everything here should be buildable at compile time.
+    const LINE: Vector<f32> = Vector::new(1.0, 2.0, 3.0);
+    const POINT: Point2d<f64> = Point2d::new(1.0, 2.0);
+    const QUARTER_TURN: Angle<f32> = Angle::from_degrees(90.0);
+    static MOTOR: Multivector<f64> = Multivector::from_parts(1.0, Vector::<f64>::ZERO, Bivector::new(0.5, 0.0, 0.0), Trivector::<f64>::ZERO);
+
+    #[test]
+    fn test_const_constructors() {
+        assert_eq!(LINE, Vector { e0: 1.0, e1: 2.0, e2: 3.0 });
+        assert_eq!(POINT, Point2d { x: 1.0, y: 2.0 });
+        assert_eq!(QUARTER_TURN.get_radians(), core::f32::consts::FRAC_PI_2);
+        assert_eq!(MOTOR.scalar, 1.0);
+    }
+
+    #[test]
+    fn test_basis_constants() {
+        assert_eq!(Multivector::<f32>::ZERO, Multivector::zero());
+        assert_eq!(Multivector::<f32>::E0, Vector::<f32>::E0.to_multivector());
+        assert_eq!(Multivector::<f64>::E20, Bivector::<f64>::E20.to_multivector());
+        assert_eq!(Multivector::<f32>::E012, Trivector::<f32>::unit().to_multivector());
+
+        // e₀ is degenerate, e₁ and e₂ square to one, and e₁e₂ = e₁₂.
+        assert_eq!(Vector::<f32>::E0.geo(&Vector::<f32>::E0), Multivector::<f32>::ZERO);
+        assert_eq!(Vector::<f32>::E1.geo(&Vector::<f32>::E1), Multivector::<f32>::ONE);
+        assert_eq!(Vector::<f32>::E1.geo(&Vector::<f32>::E2), Multivector::<f32>::E12);
+        assert_eq!(Vector::<f32>::E0.geo(&Bivector::<f32>::E12), Multivector::<f32>::E012);
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,78 @@
+#[cfg(all(test, feature = "alloc"))]
+mod parse_round_trip {
+    use alloc::string::ToString;
+
+    use crate::defs::{multivector::Multivector, vector::Vector, bivector::Bivector, trivector::Trivector};
+
+    #[test]
+    fn test_display_round_trip() {
+        let mv: Multivector<f32> = Multivector {
+            scalar: 0.5,
+            vector: Vector { e0: 2.0, e1: -3.0, e2: 4.25 },
+            bivector: Bivector { e01: 5.0, e20: -6.0, e12: 7.0 },
+            trivector: Trivector { e012: -8.0 },
+        };
+        let v: Vector<f64> = Vector { e0: 1.0, e1: -2.5, e2: 3.0 };
+        let bv: Bivector<f32> = Bivector { e01: -1.0, e20: 0.125, e12: 1.0 };
+        let tv: Trivector<f32> = Trivector { e012: 2.0 };
+
+        assert_eq!(mv.to_string().parse::<Multivector<f32>>().unwrap(), mv);
+        assert_eq!(v.to_string().parse::<Vector<f64>>().unwrap(), v);
+        assert_eq!(bv.to_string().parse::<Bivector<f32>>().unwrap(), bv);
+        assert_eq!(tv.to_string().parse::<Trivector<f32>>().unwrap(), tv);
+    }
+
+    #[test]
+    fn test_ascii_form() {
+        let mv: Multivector<f32> = "0.5 + 2e0 - 3e12 + e012".parse().unwrap();
+        let correct: Multivector<f32> = Multivector {
+            scalar: 0.5,
+            vector: Vector { e0: 2.0, e1: 0.0, e2: 0.0 },
+            bivector: Bivector { e01: 0.0, e20: 0.0, e12: -3.0 },
+            trivector: Trivector { e012: 1.0 },
+        };
+        assert_eq!(mv, correct);
+
+        // Spaces, '*', and repeated terms.
+        let v: Vector<f32> = "2 e1 + 3*e2 - e1".parse().unwrap();
+        assert_eq!(v, Vector { e0: 0.0, e1: 1.0, e2: 3.0 });
+    }
+
+    #[test]
+    fn test_basis_normalization() {
+        // e02 = -e20, e21 = -e12, e10 = -e01.
+        let bv: Bivector<f32> = "2e02 + e21 + 3e10".parse().unwrap();
+        assert_eq!(bv, Bivector { e01: -3.0, e20: -2.0, e12: -1.0 });
+
+        // Odd permutations of e012 flip the sign, even ones don't.
+        let tv: Trivector<f32> = "e021 + 2e201".parse().unwrap();
+        assert_eq!(tv, Trivector { e012: 1.0 });
+
+        // e1e1 = 1, e0e0 = 0.
+        let mv: Multivector<f32> = "3e11 + 4e00".parse().unwrap();
+        assert_eq!(mv, Multivector::from_scalar(3.0));
+    }
+}
+
+#[cfg(test)]
+mod parse_errors {
+    use crate::{defs::{multivector::Multivector, vector::Vector}, parse::ParseErrorKind};
+
+    #[test]
+    fn test_errors() {
+        let kind = |s: &str| s.parse::<Multivector<f32>>().unwrap_err().kind();
+
+        assert_eq!(kind(""), ParseErrorKind::Empty);
+        assert_eq!(kind("1 + e3"), ParseErrorKind::InvalidBasis);
+        assert_eq!(kind("1 + e"), ParseErrorKind::InvalidBasis);
+        assert_eq!(kind("1 +"), ParseErrorKind::ExpectedTerm);
+        assert_eq!(kind("2 * 3"), ParseErrorKind::ExpectedTerm);
+        assert_eq!(kind("{ 1e0"), ParseErrorKind::UnbalancedBraces);
+        assert_eq!(kind("1e0 }"), ParseErrorKind::UnbalancedBraces);
+        assert_eq!(kind("1.2.3"), ParseErrorKind::InvalidNumber);
+        assert_eq!(kind("1 x"), ParseErrorKind::UnexpectedChar('x'));
+
+        let err = "1e0 + e12".parse::<Vector<f32>>().unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::WrongGrade { expected: "vector (grade 1)" });
+    }
+}
@@ -0,0 +1,146 @@
+#[cfg(all(test, feature = "alloc"))]
+mod test_polygon_measurements {
+    use alloc::vec;
+
+    use crate::extras::{point2d::Point2d, polygon2d::{Polygon2d, Winding}};
+    use crate::tests::helpers::square;
+
+    #[test]
+    fn test_area_and_winding() {
+        let square = square(0.0, 0.0, 2.0);
+        assert_eq!(square.signed_area(), 4.0);
+        assert_eq!(square.winding(), Winding::CounterClockwise);
+
+        let reversed = square.reversed();
+        assert_eq!(reversed.signed_area(), -4.0);
+        assert_eq!(reversed.area(), 4.0);
+        assert_eq!(reversed.winding(), Winding::Clockwise);
+
+        let flat = Polygon2d::from_slice(&[Point2d::new(0.0f64, 0.0), Point2d::new(1.0, 1.0), Point2d::new(2.0, 2.0)]);
+        assert_eq!(flat.winding(), Winding::Degenerate);
+        assert_eq!(flat.centroid(), None);
+    }
+
+    #[test]
+    fn test_centroid() {
+        assert_eq!(square(0.0, 0.0, 2.0).centroid(), Some(Point2d::new(1.0, 1.0)));
+
+        // An L shape, from a 2x2 square with the top right 1x1 removed.
+        let l_shape = Polygon2d::new(vec![
+            Point2d::new(0.0f64, 0.0),
+            Point2d::new(2.0, 0.0),
+            Point2d::new(2.0, 1.0),
+            Point2d::new(1.0, 1.0),
+            Point2d::new(1.0, 2.0),
+            Point2d::new(0.0, 2.0),
+        ]);
+        let centroid = l_shape.centroid().unwrap();
+        assert!((centroid.x - 5.0 / 6.0).abs() < 1e-9);
+        assert!((centroid.y - 5.0 / 6.0).abs() < 1e-9);
+        assert_eq!(l_shape.reversed().centroid(), l_shape.centroid());
+    }
+
+    #[test]
+    fn test_perimeter_and_edges() {
+        let square = square(0.0, 0.0, 2.0);
+        assert_eq!(square.perimeter(), 8.0);
+
+        // Counterclockwise, so every vertex is on the negative (left) side of every edge line.
+        for line in square.edge_lines() {
+            for vertex in square.vertices() {
+                assert!(line.distance_to_point(&vertex.to_bivector()) <= 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_convexity() {
+        assert!(square(0.0, 0.0, 2.0).is_convex());
+        assert!(square(0.0, 0.0, 2.0).reversed().is_convex());
+
+        let arrow = Polygon2d::new(vec![
+            Point2d::new(0.0f64, 0.0),
+            Point2d::new(2.0, 1.0),
+            Point2d::new(0.0, 2.0),
+            Point2d::new(1.0, 1.0),
+        ]);
+        assert!(!arrow.is_convex());
+
+        // A pentagram turns the same way at every corner, but loops around twice.
+        let star = Polygon2d::new((0..5)
+            .map(|i| {
+                let angle = core::f64::consts::TAU * (2 * i) as f64 / 5.0;
+                Point2d::new(angle.cos(), angle.sin())
+            })
+            .collect());
+        assert!(!star.is_convex());
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod test_polygon_containment {
+    use alloc::vec;
+
+    use crate::extras::{point2d::Point2d, polygon2d::{FillRule, Polygon2d}, predicates::{orient2d, Orientation}};
+
+    #[test]
+    fn test_simple_containment() {
+        let triangle = Polygon2d::new(vec![Point2d::new(0.0f32, 0.0), Point2d::new(4.0, 0.0), Point2d::new(0.0, 4.0)]);
+        assert!(triangle.contains(&Point2d::new(1.0, 1.0), FillRule::EvenOdd));
+        assert!(triangle.contains(&Point2d::new(1.0, 1.0), FillRule::NonZero));
+        assert!(!triangle.contains(&Point2d::new(3.0, 3.0), FillRule::NonZero));
+        assert_eq!(triangle.winding_number(&Point2d::new(1.0, 1.0)), 1);
+        assert_eq!(triangle.reversed().winding_number(&Point2d::new(1.0, 1.0)), -1);
+    }
+
+    #[test]
+    fn test_fill_rules() {
+        // A pentagram's center is wound around twice.
+        let star = Polygon2d::new((0..5)
+            .map(|i| {
+                let angle = core::f64::consts::TAU * (2 * i) as f64 / 5.0;
+                Point2d::new(angle.cos(), angle.sin())
+            })
+            .collect());
+        let center = Point2d::new(0.0, 0.0);
+        assert_eq!(star.winding_number(&center), 2);
+        assert!(star.contains(&center, FillRule::NonZero));
+        assert!(!star.contains(&center, FillRule::EvenOdd));
+    }
+
+    #[test]
+    fn test_points_one_ulp_from_an_edge() {
+        // Building the edge's line rounds it, and puts (0.106, 0.207) on the inside, when it's just outside.
+        let triangle = Polygon2d::new(vec![Point2d::new(0.1f64, 0.2), Point2d::new(0.7, 0.9), Point2d::new(0.1, 0.9)]);
+        let (start, end) = (triangle.vertices()[0], triangle.vertices()[1]);
+        assert!(!triangle.contains(&Point2d::new(0.106, 0.207), FillRule::NonZero));
+
+        // Either side of the edge, an ulp at a time, it agrees with the exact orientation.
+        let mut y = f64::from_bits(0.207f64.to_bits() - 3);
+        for _ in 0..6 {
+            let point = Point2d::new(0.106, y);
+            let left = orient2d(&start, &end, &point) == Orientation::CounterClockwise;
+            assert_eq!(triangle.contains(&point, FillRule::NonZero), left, "{}", point);
+            y = f64::from_bits(y.to_bits() + 1);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod test_polygon_transforms {
+    use alloc::vec;
+
+    use crate::extras::{angle::Angle, point2d::Point2d, polygon2d::Polygon2d, transformations::{RigidTransformation, Rotor}};
+
+    #[test]
+    fn test_rotate_polygon() {
+        let triangle = Polygon2d::new(vec![Point2d::new(0.0f64, 0.0), Point2d::new(4.0, 0.0), Point2d::new(0.0, 3.0)]);
+        let rotor = Rotor::new(Point2d::new(-1.0, 2.0), Angle::from_radians(1.1));
+        let rotated = rotor.apply(&triangle);
+
+        assert!((rotated.signed_area() - triangle.signed_area()).abs() < 1e-9);
+        assert!((rotated.perimeter() - 12.0).abs() < 1e-9);
+        let expected = rotor.apply(&triangle.centroid().unwrap());
+        assert!(rotated.centroid().unwrap().distance_to(&expected) < 1e-9);
+    }
+}
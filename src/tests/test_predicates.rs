@@ -0,0 +1,108 @@
+#[cfg(test)]
+mod test_exact_predicates {
+    use crate::{defs::{vector::Vector, bivector::Bivector}, traits::OuterProduct, extras::{point2d::Point2d, predicates::{Orientation, are_concurrent, incircle, is_on_line, orient2d, orient_lines, side_of_line, turn_between}}};
+    use core::cmp::Ordering;
+
+    /// The exact orientation, for coordinates which are all multiples of 2^-53 below 32, using integers.
+    fn exact_orientation(p: &Point2d<f64>, q: &Point2d<f64>, r: &Point2d<f64>) -> Orientation {
+        let scale = |v: f64| (v * 2f64.powi(53)) as i128;
+        let (px, py, qx, qy, rx, ry) = (scale(p.x), scale(p.y), scale(q.x), scale(q.y), scale(r.x), scale(r.y));
+        match ((px - rx) * (qy - ry) - (py - ry) * (qx - rx)).cmp(&0) {
+            Ordering::Greater => Orientation::CounterClockwise,
+            Ordering::Less => Orientation::Clockwise,
+            Ordering::Equal => Orientation::Collinear,
+        }
+    }
+
+    #[test]
+    fn test_simple_orientations() {
+        let (p, q) = (Point2d::new(0.0f32, 0.0), Point2d::new(1.0, 0.0));
+        assert_eq!(orient2d(&p, &q, &Point2d::new(0.5, 1.0)), Orientation::CounterClockwise);
+        assert_eq!(orient2d(&p, &q, &Point2d::new(0.5, -1.0)), Orientation::Clockwise);
+        assert_eq!(orient2d(&p, &q, &Point2d::new(7.0, 0.0)), Orientation::Collinear);
+    }
+
+    #[test]
+    fn test_near_degenerate_orientations() {
+        // Nudging the first point around by a few ulps, near the line y = x.  The naive determinant gets a lot of
+        // these wrong; the exact one can't.
+        let q = Point2d::new(12.0f64, 12.0);
+        let r = Point2d::new(24.0f64, 24.0);
+        let ulp = f64::EPSILON / 2.0;
+        for i in 0..32 {
+            for j in 0..32 {
+                let p = Point2d::new(0.5 + i as f64 * ulp, 0.5 + j as f64 * ulp);
+                assert_eq!(orient2d(&p, &q, &r), exact_orientation(&p, &q, &r), "at {}, {}", i, j);
+            }
+        }
+    }
+
+    #[test]
+    fn test_side_of_line() {
+        // x + y - 2 = 0, with unnormalized points.
+        let line: Vector<f64> = Vector { e0: -2.0, e1: 1.0, e2: 1.0 };
+        assert_eq!(side_of_line(&line, &Bivector { e01: 3.0, e20: 3.0, e12: 3.0 }), Ordering::Equal);
+        assert!(is_on_line(&line, &Point2d::new(0.5, 1.5).to_bivector()));
+        assert_eq!(side_of_line(&line, &Point2d::new(2.0, 2.0).to_bivector()), Ordering::Greater);
+
+        // Just off the line, by less than rounding would notice in the naive sum.
+        let point = Point2d::new(1.0 + f64::EPSILON, 1.0).to_bivector();
+        assert_eq!(side_of_line(&line, &point), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_concurrency() {
+        // Three lines through (1, 1).
+        let l1: Vector<f64> = Vector { e0: -1.0, e1: 1.0, e2: 0.0 };
+        let l2: Vector<f64> = Vector { e0: -1.0, e1: 0.0, e2: 1.0 };
+        let l3: Vector<f64> = Vector { e0: -3.0, e1: 1.0, e2: 2.0 };
+        assert!(are_concurrent(&l1, &l2, &l3));
+
+        let nudged: Vector<f64> = Vector { e0: -3.0 + 4.0 * f64::EPSILON, e1: 1.0, e2: 2.0 };
+        assert!(!are_concurrent(&l1, &l2, &nudged));
+
+        // Parallel lines meet at infinity.
+        let p1: Vector<f64> = Vector { e0: 1.0, e1: 1.0, e2: 0.0 };
+        let p2: Vector<f64> = Vector { e0: 2.0, e1: 1.0, e2: 0.0 };
+        let p3: Vector<f64> = Vector { e0: 3.0, e1: -2.0, e2: 0.0 };
+        assert!(are_concurrent(&p1, &p2, &p3));
+    }
+
+    #[test]
+    fn test_line_orientation() {
+        // The x axis, heading (-b, a) = (-1, 0), and the y axis, heading (0, 1), which is a clockwise turn.
+        let x_axis: Vector<f64> = Vector { e0: 0.0, e1: 0.0, e2: 1.0 };
+        let y_axis: Vector<f64> = Vector { e0: 0.0, e1: 1.0, e2: 0.0 };
+        assert_eq!(turn_between(&x_axis, &y_axis), Orientation::Clockwise);
+        assert_eq!(turn_between(&y_axis, &x_axis), Orientation::CounterClockwise);
+        assert_eq!(turn_between(&x_axis, &(x_axis.clone() * -3.0)), Orientation::Collinear);
+
+        // The axes meet at the origin, which is on the positive side of x + y + 1.
+        let line: Vector<f64> = Vector { e0: 1.0, e1: 1.0, e2: 1.0 };
+        let meet = x_axis.wedge(&y_axis);
+        assert_eq!(orient_lines(&x_axis, &y_axis, &line), side_of_line(&line, &meet));
+        assert_eq!(orient_lines(&y_axis, &x_axis, &line), side_of_line(&line, &meet).reverse());
+
+        // Nearly parallel, by one ulp.
+        let nudged: Vector<f64> = Vector { e0: 5.0, e1: f64::EPSILON, e2: 1.0 };
+        assert_eq!(turn_between(&x_axis, &nudged), Orientation::Clockwise);
+    }
+
+    #[test]
+    fn test_incircle() {
+        // The unit circle, through three counterclockwise points.
+        let (a, b, c) = (Point2d::new(1.0f64, 0.0), Point2d::new(0.0, 1.0), Point2d::new(-1.0, 0.0));
+        assert_eq!(incircle(&a, &b, &c, &Point2d::new(0.0, 0.0)), Ordering::Greater);
+        assert_eq!(incircle(&a, &b, &c, &Point2d::new(2.0, 2.0)), Ordering::Less);
+        assert_eq!(incircle(&a, &b, &c, &Point2d::new(0.0, -1.0)), Ordering::Equal);
+
+        // Clockwise flips the answer.
+        assert_eq!(incircle(&c, &b, &a, &Point2d::new(0.0, 0.0)), Ordering::Less);
+
+        // On the circle, nudged by an ulp either way, which is well below the naive determinant's rounding.
+        let ulp = f64::EPSILON / 2.0;
+        assert_eq!(incircle(&a, &b, &c, &Point2d::new(0.0, -1.0 + ulp)), Ordering::Greater);
+        assert_eq!(incircle(&a, &b, &c, &Point2d::new(0.0, -1.0 - 2.0 * ulp)), Ordering::Less);
+        assert_eq!(incircle(&a, &b, &c, &Point2d::new(1.0 - ulp, 0.0)), Ordering::Greater);
+    }
+}
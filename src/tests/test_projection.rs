@@ -0,0 +1,42 @@
+#[cfg(test)]
+mod test_projections {
+    use crate::{defs::{vector::Vector, bivector::Bivector}, extras::point2d::Point2d};
+
+    // The line 3x + 4y - 10 = 0.
+    fn line() -> Vector<f32> {
+        Vector { e0: -10.0, e1: 3.0, e2: 4.0 }
+    }
+
+    #[test]
+    fn test_project_point() {
+        // (6, 3) is 4 units from the line, along the normal (0.6, 0.8).
+        let foot = Point2d::new(6.0f32, 3.0).project_onto(&line());
+        assert_eq!(foot, Point2d::new(3.6, -0.2));
+        assert!(foot.distance_to_line(&line()).abs() < 1e-6);
+
+        // Weight shouldn't matter.
+        let heavy: Bivector<f32> = Point2d::new(6.0f32, 3.0).to_bivector() * 2.0;
+        assert_eq!(Point2d::from_bivector(&heavy.project_onto(&line())), foot);
+    }
+
+    #[test]
+    fn test_perpendicular_and_parallel() {
+        let point = Point2d::new(6.0f32, 3.0);
+
+        let perpendicular = point.perpendicular_to(&line());
+        assert_eq!(perpendicular, Vector { e0: 15.0, e1: -4.0, e2: 3.0 });
+        assert_eq!(point.distance_to_line(&perpendicular), 0.0);
+
+        let parallel = point.parallel_to(&line());
+        assert_eq!(parallel, Vector { e0: -30.0, e1: 3.0, e2: 4.0 });
+        assert_eq!(parallel.distance_to_parallel(&line()), 4.0);
+    }
+
+    #[test]
+    fn test_reject() {
+        let rejection = Point2d::new(6.0f32, 3.0).to_bivector().reject_from(&line());
+        assert_eq!(rejection.e12, 0.0);
+        assert!((rejection.e20 - 2.4).abs() < 1e-6);
+        assert!((rejection.e01 - 3.2).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,108 @@
+#[cfg(all(test, feature = "alloc"))]
+mod test_ray_casts {
+    use alloc::vec;
+
+    use crate::extras::{
+        angle::Angle,
+        capsule2d::Capsule2d,
+        circle2d::Circle2d,
+        direction2d::Direction2d,
+        point2d::Point2d,
+        ray2d::Ray2d,
+        raycast::{raycast, Local, Raycast, Shape},
+        segment2d::Segment2d,
+        transformations::{Motor, Rotor},
+    };
+    use crate::tests::helpers::{assert_close, square};
+
+    /// The way the hit's normal line points.
+    fn normal_direction(normal: &crate::defs::vector::Vector<f64>) -> Direction2d<f64> {
+        Direction2d::new(-normal.e2, normal.e1)
+    }
+
+    #[test]
+    fn test_each_shape() {
+        let ray = Ray2d::new(Point2d::new(0.0f64, 0.0), Direction2d::new(2.0, 0.0));
+
+        let hit = Segment2d::new(Point2d::new(3.0, -1.0), Point2d::new(3.0, 1.0)).raycast(&ray).unwrap();
+        assert!((hit.distance - 3.0).abs() < 1e-9);
+        assert_close(hit.point, Point2d::new(3.0, 0.0));
+        let normal = normal_direction(&hit.normal);
+        assert!((normal.x + 1.0).abs() < 1e-9 && normal.y.abs() < 1e-9);
+
+        let hit = square(2.0, -0.5, 1.0).raycast(&ray).unwrap();
+        assert!((hit.distance - 2.0).abs() < 1e-9);
+
+        let hit = Circle2d::new(Point2d::new(5.0, 0.6), 1.0).raycast(&ray).unwrap();
+        assert!((hit.distance - 4.2).abs() < 1e-9);
+        let normal = normal_direction(&hit.normal);
+        assert!((normal.x + 0.8).abs() < 1e-9 && (normal.y + 0.6).abs() < 1e-9);
+
+        let capsule = Capsule2d::new(Point2d::new(4.0, 2.0), Point2d::new(4.0, 5.0), 0.5);
+        assert!(capsule.raycast(&ray).is_none());
+        let up = Ray2d::new(Point2d::new(4.0, 0.0), Direction2d::new(0.0, 1.0));
+        let hit = capsule.raycast(&up).unwrap();
+        assert!((hit.distance - 1.5).abs() < 1e-9);
+        let side = Ray2d::new(Point2d::new(0.0, 3.0), Direction2d::new(1.0, 0.0));
+        assert!((capsule.raycast(&side).unwrap().distance - 3.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_nearest_shape() {
+        let shapes: vec::Vec<Shape<f64>> = vec![
+            Circle2d::new(Point2d::new(10.0, 0.0), 1.0).into(),
+            square(4.0, -1.0, 2.0).into(),
+            Segment2d::new(Point2d::new(6.0, -3.0), Point2d::new(6.0, 3.0)).into(),
+            Capsule2d::new(Point2d::new(-5.0, -1.0), Point2d::new(-5.0, 1.0), 0.5).into(),
+        ];
+        let ray = Ray2d::new(Point2d::new(0.0, 0.0), Direction2d::new(1.0, 0.0));
+        let hit = raycast(&ray, &shapes).unwrap();
+        assert_eq!(hit.shape, 1);
+        assert!((hit.distance - 4.0).abs() < 1e-9);
+
+        let backwards = Ray2d::new(Point2d::new(0.0, 0.0), Direction2d::new(-1.0, 0.0));
+        assert_eq!(raycast(&backwards, &shapes).unwrap().shape, 3);
+        let up = Ray2d::new(Point2d::new(0.0, 0.0), Direction2d::new(0.0, 1.0));
+        assert!(raycast(&up, &shapes).is_none());
+
+        // Different kinds of shape can be mixed without the enum, too.
+        let circle = Circle2d::new(Point2d::new(3.0, 0.0), 1.0);
+        let segment = Segment2d::new(Point2d::new(1.0, -1.0), Point2d::new(1.0, 1.0));
+        let mixed: [&dyn Raycast<f64>; 2] = [&circle, &segment];
+        assert_eq!(raycast(&ray, &mixed).unwrap().shape, 1);
+    }
+
+    #[test]
+    fn test_starting_inside() {
+        // Picking: a point inside a shape hits it straight away.
+        let ray = Ray2d::new(Point2d::new(0.5, 0.5), Direction2d::new(1.0, 1.0));
+        let hit = square(0.0, 0.0, 1.0).raycast(&ray).unwrap();
+        assert_eq!(hit.distance, 0.0);
+        assert_close(hit.point, Point2d::new(0.5, 0.5));
+
+        let hit = Circle2d::new(Point2d::new(0.0, 0.0), 1.0).raycast(&ray).unwrap();
+        assert_eq!(hit.distance, 0.0);
+    }
+
+    #[test]
+    fn test_local_shapes() {
+        // A capsule lying along the x axis, stood up and moved to (5, 0).
+        let capsule = Capsule2d::new(Point2d::new(-1.0, 0.0), Point2d::new(1.0, 0.0), 0.25);
+        let rotor = Rotor::new(Point2d::new(0.0, 0.0), Angle::from_radians(core::f64::consts::FRAC_PI_2));
+        let motor = Motor::from_direction(Direction2d::new(5.0, 0.0));
+        let placed = Local::from_slice(capsule, &[rotor.get_transformer(), motor.get_transformer()]);
+
+        let ray = Ray2d::new(Point2d::new(0.0, 0.9), Direction2d::new(1.0, 0.0));
+        let hit = placed.raycast(&ray).unwrap();
+        assert!((hit.distance - 4.75).abs() < 1e-9);
+        assert_close(hit.point, Point2d::new(4.75, 0.9));
+        let normal = normal_direction(&hit.normal);
+        assert!((normal.x + 1.0).abs() < 1e-9);
+
+        // Only moved, it's still lying down.
+        let placed = Local::new(capsule, &motor);
+        let ray = Ray2d::new(Point2d::new(0.0, 0.0), Direction2d::new(1.0, 0.0));
+        assert!((placed.raycast(&ray).unwrap().distance - 3.75).abs() < 1e-9);
+        assert_eq!(placed.shape(), &capsule);
+    }
+}
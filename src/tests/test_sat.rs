@@ -0,0 +1,106 @@
+#[cfg(all(test, feature = "alloc"))]
+mod test_separating_axes {
+    use alloc::vec;
+
+    use crate::extras::{
+        angle::Angle,
+        direction2d::Direction2d,
+        point2d::Point2d,
+        polygon2d::Polygon2d,
+        sat::{collide, ConvexPolygon},
+        transformations::{Motor, MultiTransform, RigidTransformation, Rotor},
+    };
+
+    /// A box centred on the origin.
+    fn block(width: f64, height: f64) -> ConvexPolygon<f64> {
+        let (x, y) = (width / 2.0, height / 2.0);
+        ConvexPolygon::new(&Polygon2d::new(vec![
+            Point2d::new(-x, -y),
+            Point2d::new(x, -y),
+            Point2d::new(x, y),
+            Point2d::new(-x, y),
+        ]))
+    }
+
+    fn moved(polygon: &ConvexPolygon<f64>, x: f64, y: f64) -> ConvexPolygon<f64> {
+        Motor::from_direction(Direction2d::new(x, y)).apply(polygon)
+    }
+
+    #[test]
+    fn test_edges_and_vertices() {
+        // Clockwise input comes out counterclockwise, with outward normals.
+        let polygon = ConvexPolygon::new(&Polygon2d::new(vec![
+            Point2d::new(0.0f64, 0.0),
+            Point2d::new(0.0, 1.0),
+            Point2d::new(1.0, 1.0),
+            Point2d::new(1.0, 0.0),
+        ]));
+        assert_eq!(polygon.vertices().len(), 4);
+        for (edge, vertex) in polygon.edges().iter().zip(polygon.vertices()) {
+            assert!((edge.e1 * edge.e1 + edge.e2 * edge.e2 - 1.0).abs() < 1e-12);
+            assert!(edge.e1 * (0.5 - vertex.e20) + edge.e2 * (0.5 - vertex.e01) < 0.0);
+        }
+        assert!((polygon.to_polygon().signed_area() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_stacked_boxes() {
+        let ground = block(10.0, 1.0);
+        let crate_box = moved(&block(1.0, 1.0), 0.3, 0.95);
+
+        let manifold = collide(&ground, &crate_box).unwrap();
+        assert!(!manifold.flipped);
+        assert!((manifold.normal.e2 - 1.0).abs() < 1e-9 && manifold.normal.e1.abs() < 1e-9);
+        assert!((manifold.depth - 0.05).abs() < 1e-9);
+        assert_eq!(manifold.contacts.len(), 2);
+        let mut xs: vec::Vec<f64> = manifold.contacts.iter().map(|contact| contact.point.e20).collect();
+        xs.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert!((xs[0] + 0.2).abs() < 1e-9 && (xs[1] - 0.8).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_incident_edge_is_clipped() {
+        // A wide plank over a post only touches along the post's width.
+        let post = block(1.0, 1.0);
+        let plank = moved(&block(4.0, 0.5), 1.5, 0.7);
+
+        let manifold = collide(&post, &plank).unwrap();
+        assert!(!manifold.flipped);
+        assert!((manifold.normal.e2 - 1.0).abs() < 1e-9);
+        assert_eq!(manifold.contacts.len(), 2);
+        for contact in &manifold.contacts {
+            assert!(contact.point.e20 >= -0.5 - 1e-9 && contact.point.e20 <= 0.5 + 1e-9);
+            assert!((contact.depth - 0.05).abs() < 1e-9);
+        }
+
+        // The other way round, the plank's edge is as good, so it's the reference edge, and the normal still points
+        // from the first polygon to the second.
+        let manifold = collide(&plank, &post).unwrap();
+        assert!((manifold.normal.e2 + 1.0).abs() < 1e-9);
+        assert_eq!(manifold.contacts.len(), 2);
+    }
+
+    #[test]
+    fn test_tilted_box() {
+        // Standing on one corner gives one contact.
+        let ground = block(10.0, 1.0);
+        let rotor = Rotor::new(Point2d::new(0.0, 0.0), Angle::from_radians(core::f64::consts::FRAC_PI_4));
+        let motor = Motor::from_direction(Direction2d::new(0.0, 0.5 + 0.5 * 2f64.sqrt() - 0.1));
+        let transformation = MultiTransform::from_slice(&[rotor.get_transformer(), motor.get_transformer()]);
+        let diamond = transformation.apply(&block(1.0, 1.0));
+
+        let manifold = collide(&ground, &diamond).unwrap();
+        assert_eq!(manifold.contacts.len(), 1);
+        assert!((manifold.depth - 0.1).abs() < 1e-9);
+        assert!(manifold.contacts[0].point.e20.abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_apart_and_touching() {
+        let a = block(1.0, 1.0);
+        assert!(collide(&a, &moved(&a, 1.5, 0.0)).is_none());
+        assert!(collide(&a, &moved(&a, 1.0, 0.0)).is_none());
+        assert!(collide(&a, &moved(&a, 0.9, 2.0)).is_none());
+        assert!(collide(&a, &moved(&a, 0.9, 0.0)).is_some());
+    }
+}
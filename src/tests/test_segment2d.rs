@@ -0,0 +1,111 @@
+#[cfg(test)]
+mod test_segments {
+    use crate::extras::{point2d::Point2d, segment2d::{Crossing, Segment2d}};
+
+    #[test]
+    fn test_length_and_points() {
+        let segment = Segment2d::new(Point2d::new(1.0f32, 1.0), Point2d::new(4.0, 5.0));
+        assert_eq!(segment.length(), 5.0);
+        assert_eq!(segment.point_at(0.5), Point2d::new(2.5, 3.0));
+    }
+
+    #[test]
+    fn test_closest_point() {
+        let segment = Segment2d::new(Point2d::new(0.0f32, 0.0), Point2d::new(4.0, 0.0));
+        assert_eq!(segment.closest_point(&Point2d::new(1.0, 3.0)), Point2d::new(1.0, 0.0));
+        // Past the ends, the closest point is the end.
+        assert_eq!(segment.closest_point(&Point2d::new(-2.0, 1.0)), Point2d::new(0.0, 0.0));
+        assert_eq!(segment.closest_point(&Point2d::new(9.0, -1.0)), Point2d::new(4.0, 0.0));
+    }
+
+    #[test]
+    fn test_crossing_segments() {
+        let s1 = Segment2d::new(Point2d::new(0.0f32, 0.0), Point2d::new(4.0, 4.0));
+        let s2 = Segment2d::new(Point2d::new(0.0f32, 4.0), Point2d::new(2.0, 2.0));
+        assert_eq!(s1.intersect(&s2), Some(Crossing { point: Point2d::new(2.0, 2.0), t: 0.5, u: 1.0 }));
+
+        // The lines cross, but past the end of the second segment.
+        let s3 = Segment2d::new(Point2d::new(0.0f32, 4.0), Point2d::new(1.0, 3.0));
+        assert_eq!(s1.intersect(&s3), None);
+    }
+
+    #[test]
+    fn test_parallel_segments() {
+        let s1 = Segment2d::new(Point2d::new(0.0f32, 0.0), Point2d::new(4.0, 0.0));
+        let apart = Segment2d::new(Point2d::new(0.0f32, 1.0), Point2d::new(4.0, 1.0));
+        assert_eq!(s1.intersect(&apart), None);
+
+        // Collinear and overlapping, in the opposite direction.  The first shared point along s1 is (2, 0).
+        let overlapping = Segment2d::new(Point2d::new(6.0f32, 0.0), Point2d::new(2.0, 0.0));
+        assert_eq!(s1.intersect(&overlapping), Some(Crossing { point: Point2d::new(2.0, 0.0), t: 0.5, u: 1.0 }));
+
+        let disjoint = Segment2d::new(Point2d::new(5.0f32, 0.0), Point2d::new(7.0, 0.0));
+        assert_eq!(s1.intersect(&disjoint), None);
+    }
+}
+
+#[cfg(test)]
+mod test_rays {
+    use crate::extras::{direction2d::Direction2d, line2d::Line2d, point2d::Point2d, ray2d::Ray2d, segment2d::{Crossing, Segment2d}};
+
+    #[test]
+    fn test_ray_line() {
+        let ray = Ray2d::new(Point2d::new(0.0f32, 0.0), Direction2d::new(2.0, 0.0));
+        // x = 3, pointing up.
+        let wall = Line2d::from_points(Point2d::new(3.0, 0.0), Point2d::new(3.0, 1.0));
+        assert_eq!(ray.intersect_line(&wall), Some(Crossing { point: Point2d::new(3.0, 0.0), t: 1.5, u: 0.0 }));
+
+        // Behind the ray, or parallel to it.
+        let behind = Line2d::from_points(Point2d::new(-3.0, 0.0), Point2d::new(-3.0, 1.0));
+        assert_eq!(ray.intersect_line(&behind), None);
+        assert_eq!(ray.intersect_line(&Line2d::from_slope_intercept(0.0, 1.0)), None);
+    }
+
+    #[test]
+    fn test_ray_segment() {
+        let ray = Ray2d::through(Point2d::new(0.0f32, 0.0), Point2d::new(1.0, 1.0));
+        let segment = Segment2d::new(Point2d::new(0.0f32, 4.0), Point2d::new(4.0, 0.0));
+        assert_eq!(ray.intersect_segment(&segment), Some(Crossing { point: Point2d::new(2.0, 2.0), t: 2.0, u: 0.5 }));
+
+        let short = Segment2d::new(Point2d::new(0.0f32, 4.0), Point2d::new(1.0, 3.0));
+        assert_eq!(ray.intersect_segment(&short), None);
+    }
+
+    #[test]
+    fn test_collinear_ray_segment() {
+        let ray = Ray2d::new(Point2d::new(0.0f32, 0.0), Direction2d::new(1.0, 0.0));
+        let ahead = Segment2d::new(Point2d::new(5.0f32, 0.0), Point2d::new(3.0, 0.0));
+        assert_eq!(ray.intersect_segment(&ahead), Some(Crossing { point: Point2d::new(3.0, 0.0), t: 3.0, u: 1.0 }));
+
+        let behind = Segment2d::new(Point2d::new(-5.0f32, 0.0), Point2d::new(-3.0, 0.0));
+        assert_eq!(ray.intersect_segment(&behind), None);
+    }
+}
+
+#[cfg(test)]
+mod test_segment_transforms {
+    use crate::extras::{angle::Angle, direction2d::Direction2d, point2d::Point2d, ray2d::Ray2d, segment2d::Segment2d, transformations::{Motor, RigidTransformation, Rotor}};
+
+    #[test]
+    fn test_transform_segment() {
+        let segment = Segment2d::new(Point2d::new(1.0f64, 0.0), Point2d::new(4.0, 4.0));
+        let motor = Motor::from_direction(Direction2d::new(-1.0, 2.0));
+        let rotor = Rotor::new(Point2d::new(3.0, -1.0), Angle::from_radians(0.7));
+
+        let moved = rotor.apply(&motor.apply(&segment));
+        assert!((moved.length() - 5.0).abs() < 1e-9);
+
+        let moved_start = motor.apply(&segment.start);
+        assert!((moved_start.x).abs() < 1e-9 && (moved_start.y - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_transform_ray() {
+        let ray = Ray2d::new(Point2d::new(1.0f64, 0.0), Direction2d::new(1.0, 0.0));
+        let motor = Motor::from_direction(Direction2d::new(0.0, 3.0));
+
+        let moved = motor.apply(&ray);
+        assert!((moved.origin.x - 1.0).abs() < 1e-9 && (moved.origin.y - 3.0).abs() < 1e-9);
+        assert_eq!(moved.direction, ray.direction);
+    }
+}
@@ -0,0 +1,94 @@
+#[cfg(test)]
+mod symbolic_scalar {
+    use crate::{defs::{vector::Vector, bivector::Bivector, multivector::Multivector}, traits::{GeometricProduct, RegressiveProduct, OuterProduct, Normalize}, symbolic::Sym};
+
+    #[test]
+    fn test_simplification() {
+        let x = Sym::var("x");
+        let y = Sym::var("y");
+        let one = Sym::constant(1.0);
+
+        assert_eq!((x + y) - x, y);
+        assert_eq!((x + y) * (x - y), x * x - y * y);
+        assert_eq!(x * y / x, y);
+        assert_eq!((x * x).to_string(), "x^2");
+        assert_eq!(((x + one) / (x - one)).to_latex(), "\\frac{1 + x}{-1 + x}");
+        assert_eq!((Sym::constant(2.0) / (x * x * y)).to_rust(), "2.0/(x*x*y)");
+    }
+
+    #[test]
+    fn test_geometric_product() {
+        let product = Multivector::symbolic("a").geo(&Multivector::symbolic("b"));
+        assert_eq!(
+            product.vector.e0.to_string(),
+            "a*b0 + a0*b + a01*b1 - a012*b12 - a1*b01 - a12*b012 + a2*b20 - a20*b2"
+        );
+
+        // Plugging numbers back in matches the float implementation.
+        let mv1: Multivector<f64> = crate::mv![0.5, 2 e0, 3 e1, 4 e2, 5 e01, 6 e20, 7 e12, 8 e012];
+        let mv2: Multivector<f64> = crate::mv![2, 1 e0, 2 e1, 3 e2, 3 e01, 2 e20, 1 e12, 2 e012];
+        let env = |name: &str| {
+            let (mv, suffix) = if let Some(s) = name.strip_prefix('a') { (&mv1, s) } else { (&mv2, &name[1..]) };
+            match suffix {
+                "" => mv.scalar,
+                "0" => mv.vector.e0, "1" => mv.vector.e1, "2" => mv.vector.e2,
+                "01" => mv.bivector.e01, "20" => mv.bivector.e20, "12" => mv.bivector.e12,
+                _ => mv.trivector.e012,
+            }
+        };
+        assert_eq!(product.vector.e0.eval(env), mv1.geo(&mv2).vector.e0);
+    }
+
+    #[test]
+    fn test_regressive_product_is_incident() {
+        // The join of two points should be the line from the first to the second, for any two points.
+        let p: Bivector<Sym> = Bivector::symbolic("p");
+        let q: Bivector<Sym> = Bivector::symbolic("q");
+        let line = p.regressive(&q);
+
+        assert_eq!(line.wedge(&p).e012, Sym::constant(0.0));
+        assert_eq!(line.wedge(&q).e012, Sym::constant(0.0));
+
+        // With unit weights, its direction (-e2, e1) is q - p, so it runs from p to q, and its weight is the distance
+        // between them.
+        let ((px, py, pw), (qx, qy, qw)) = ((p.e20, p.e01, p.e12), (q.e20, q.e01, q.e12));
+        assert_eq!(line.e1, pw * qy - py * qw);
+        assert_eq!(line.e2, px * qw - pw * qx);
+        assert_eq!(line.e0, py * qx - px * qy);
+    }
+
+    #[test]
+    fn test_latex() {
+        let normalized = Vector::symbolic("a").normalized();
+        assert_eq!(normalized.e1.to_latex(), "\\frac{a_{1}}{\\sqrt{a_{1}^{2} + a_{2}^{2}}}");
+        assert_eq!(num_traits::Float::sin(Sym::var("t")).to_latex(), "\\sin\\left(t\\right)");
+    }
+
+    #[test]
+    fn test_eval_with_nested_expressions() {
+        // The lookup evaluates another expression, which needs the table while the outer one is being evaluated.
+        let x = Sym::var("x");
+        let y = Sym::var("y");
+        let radius = num_traits::Float::sqrt(x * x + y * y);
+        let scaled = Sym::var("r") * Sym::constant(2.0) + num_traits::Float::sin(x);
+        let value = scaled.eval(|name| match name {
+            "r" => radius.eval(|name| if name == "x" { 3.0 } else { 4.0 }),
+            _ => 0.0,
+        });
+        assert_eq!(value, 10.0);
+    }
+
+    #[test]
+    fn test_powers() {
+        let x = Sym::var("x");
+        let y = Sym::var("y");
+        assert_eq!(num_traits::Float::powi(x + y, 3), (x + y) * (x + y) * (x + y));
+        assert_eq!(num_traits::Float::powi(x, -2), Sym::constant(1.0) / (x * x));
+        assert_eq!(num_traits::Float::powf(x, Sym::constant(5.0)), x * x * x * x * x);
+
+        // Huge powers aren't multiplied out.
+        let huge = num_traits::Float::powf(x, Sym::constant(1e9));
+        assert_eq!(huge.to_string(), "powf(x, 1000000000)");
+        assert_eq!(huge.eval(|_| 1.0), 1.0);
+    }
+}
@@ -60,5 +60,50 @@ mod test_motors {
 
 #[cfg(test)]
 mod test_multitransform {
+    use crate::{
+        defs::bivector::Bivector,
+        extras::{
+            angle::Angle,
+            transformations::{Rotor, Motor, MultiTransform, RigidTransformation},
+            point2d::Point2d}
+        };
+
+    #[test]
+    fn test_from_slice_matches_sequential() {
+        let rotor = Rotor::new(Point2d::new(1.0f32, 2.0), Angle::from_degrees(30.0));
+        let motor = Motor::new(1.0f32, 0.0, 3.0);
+        let point = Bivector { e01: -4.2f32, e20: 3.2, e12: 1.0 };
+
+        let combined = MultiTransform::from_slice(&[rotor.get_transformer(), motor.get_transformer()]);
+        let result = combined.apply(&point);
+        let expected = motor.apply(&rotor.apply(&point));
+
+        assert!((result.e01 - expected.e01).abs() < 1e-5);
+        assert!((result.e20 - expected.e20).abs() < 1e-5);
+        assert!((result.e12 - expected.e12).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_inverse_undoes() {
+        let rotor = Rotor::new(Point2d::new(1.0f32, 2.0), Angle::from_degrees(30.0));
+        let motor = Motor::new(1.0f32, 0.0, 3.0);
+        let point = Bivector { e01: -4.2f32, e20: 3.2, e12: 1.0 };
+
+        let combined = MultiTransform::from_slice(&[rotor.get_transformer(), motor.get_transformer()]);
+        let result = combined.inverse().apply(&combined.apply(&point));
+        assert!((result.e01 - point.e01).abs() < 1e-5);
+        assert!((result.e20 - point.e20).abs() < 1e-5);
 
+        let result = motor.inverse().apply(&motor.apply(&point));
+        assert!((result.e01 - point.e01).abs() < 1e-5);
+        assert!((result.e20 - point.e20).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_empty_is_identity() {
+        let point = Bivector { e01: -4.2f32, e20: 3.2, e12: 1.0 };
+        let identity = MultiTransform::from_slice(&[]);
+        assert_eq!(identity.apply(&point), point);
+        assert_eq!(identity.inverse().apply(&point), point);
+    }
 }
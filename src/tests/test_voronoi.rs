@@ -0,0 +1,117 @@
+#[cfg(all(test, feature = "alloc"))]
+mod test_voronoi_diagrams {
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use core::cmp::Ordering;
+
+    use crate::extras::{
+        point2d::Point2d,
+        predicates::side_of_line,
+        voronoi::{bisector, voronoi, voronoi_clipped},
+    };
+    use crate::tests::helpers::square;
+
+    #[test]
+    fn test_bisector() {
+        let (p, q) = (Point2d::new(1.0, 1.0), Point2d::new(3.0, 1.0));
+        let line = bisector(&p, &q);
+        assert_eq!(side_of_line(&line, &Point2d::new(2.0, 7.0).to_bivector()), Ordering::Equal);
+        assert_eq!(side_of_line(&line, &p.to_bivector()), Ordering::Less);
+        assert_eq!(side_of_line(&line, &q.to_bivector()), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_cells() {
+        // The corners of a square and its centre, whose cell is a diamond.
+        let points = [
+            Point2d::new(0.0f64, 0.0),
+            Point2d::new(2.0, 0.0),
+            Point2d::new(2.0, 2.0),
+            Point2d::new(0.0, 2.0),
+            Point2d::new(1.0, 1.0),
+        ];
+        let cells = voronoi(&points);
+        assert_eq!(cells.len(), 5);
+
+        let centre = &cells[4];
+        assert!(centre.is_bounded());
+        let diamond = centre.to_polygon().unwrap();
+        assert_eq!(diamond.len(), 4);
+        assert_eq!(diamond.area(), 2.0);
+        for corner in [Point2d::new(1.0, 0.0), Point2d::new(2.0, 1.0), Point2d::new(1.0, 2.0), Point2d::new(0.0, 1.0)] {
+            assert!(diamond.vertices().contains(&corner));
+        }
+        let mut neighbours: Vec<usize> = centre.edges().iter().map(|edge| edge.neighbour).collect();
+        neighbours.sort();
+        assert_eq!(neighbours, vec![0, 1, 2, 3]);
+
+        // The corners' cells go off to infinity.  The bottom left one comes in from below along x = 1, cuts the corner
+        // off along x + y = 1, and leaves to the left along y = 1.
+        let corner = &cells[0];
+        assert!(!corner.is_bounded() && corner.to_polygon().is_none());
+        let vertices = corner.vertices();
+        assert_eq!(vertices.len(), 4);
+        assert_eq!((vertices[0].e12, vertices[3].e12), (0.0, 0.0));
+        assert!(vertices[0].e20.abs() < 1e-12 && vertices[0].e01 < 0.0);
+        assert_eq!(Point2d::from_bivector(&vertices[1]), Point2d::new(1.0, 0.0));
+        assert_eq!(Point2d::from_bivector(&vertices[2]), Point2d::new(0.0, 1.0));
+        assert!(vertices[3].e20 < 0.0 && vertices[3].e01.abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_cocircular() {
+        // Every square in a grid has its corners on one circle, but the middle cell is still a square, not an
+        // octagon with edges of no length.
+        let grid: Vec<Point2d<f64>> = (0..9).map(|i| Point2d::new((i % 3) as f64, (i / 3) as f64)).collect();
+        let cells = voronoi(&grid);
+        let middle = cells[4].to_polygon().unwrap();
+        assert_eq!(middle.len(), 4);
+        assert_eq!(middle.area(), 1.0);
+        assert!(cells.iter().enumerate().all(|(i, cell)| cell.is_bounded() == (i == 4)));
+    }
+
+    #[test]
+    fn test_clipping() {
+        let points = [
+            Point2d::new(1.0, 1.0),
+            Point2d::new(3.0, 1.5),
+            Point2d::new(2.0, 3.0),
+            Point2d::new(3.5, 3.5),
+            Point2d::new(0.5, 3.0),
+            Point2d::new(3.0, 1.5),
+            Point2d::new(9.0, 9.0),
+        ];
+        let bounds = square(0.0, 0.0, 4.0);
+        let territories = voronoi_clipped(&points, &bounds);
+
+        // Everything inside is someone's, and each point is inside its own territory.
+        let total: f64 = territories.iter().take(5).map(|territory| territory.area()).sum();
+        assert!((total - 16.0).abs() < 1e-12);
+        for (point, territory) in points.iter().zip(&territories).take(5) {
+            assert_ne!(territory.winding_number(point), 0);
+        }
+
+        // A duplicate shares the first one's territory, and a point outside can miss the bounds entirely.
+        assert_eq!(territories[5], territories[1]);
+        assert!(territories[6].is_empty());
+    }
+
+    #[test]
+    fn test_collinear() {
+        // On one line, the cells are strips.
+        let points = [Point2d::new(2.0, 0.0), Point2d::new(0.0, 0.0), Point2d::new(1.0, 0.0)];
+        let cells = voronoi(&points);
+        assert!(cells.iter().all(|cell| !cell.is_bounded()));
+        assert_eq!(cells[2].edges().len(), 2);
+        assert_eq!(cells[0].edges().len(), 1);
+
+        let territories = voronoi_clipped(&points, &square(-1.0, -1.0, 4.0));
+        let areas: Vec<f64> = territories.iter().map(|territory| territory.area()).collect();
+        assert_eq!(areas, vec![6.0, 6.0, 4.0]);
+
+        // A lone point has the whole plane.
+        let alone = voronoi(&[Point2d::new(5.0, 5.0)]);
+        assert!(alone[0].edges().is_empty());
+        assert_eq!(alone[0].clip(&square(0.0, 0.0, 1.0)).area(), 1.0);
+    }
+}